@@ -0,0 +1,95 @@
+// benches/graph_build.rs
+// Benchmark: graph_build
+// Purpose: Compare the interned graph builder against the original string-keyed implementation on the real and an enlarged dataset.
+// Run with: cargo bench --bench graph_build
+
+use nba_network::data_loader::{load_players, PlayerId, PlayerSeason};
+use nba_network::graph_builder::build_player_graph;
+use petgraph::graph::{Graph, NodeIndex};
+use petgraph::Undirected;
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Original implementation: clones ids into every map and calls find_edge twice per pair
+fn legacy_build(players: &[PlayerSeason]) -> Graph<PlayerId, usize, Undirected> {
+    let mut graph = Graph::new_undirected();
+    let mut node_indices: HashMap<PlayerId, NodeIndex> = HashMap::new();
+    let mut teammates_map: HashMap<(PlayerId, PlayerId), usize> = HashMap::new();
+
+    let mut team_season_map: HashMap<(String, String), Vec<PlayerId>> = HashMap::new();
+    for ps in players {
+        team_season_map
+            .entry((ps.team.clone(), ps.season.to_string()))
+            .or_default()
+            .push(ps.player_id.clone());
+    }
+
+    for (_team_season, player_list) in team_season_map {
+        for i in 0..player_list.len() {
+            let p1 = &player_list[i];
+            let idx1 = *node_indices.entry(p1.clone()).or_insert_with(|| graph.add_node(p1.clone()));
+
+            for p2 in &player_list[i + 1..] {
+                let idx2 = *node_indices.entry(p2.clone()).or_insert_with(|| graph.add_node(p2.clone()));
+
+                let key = if p1 < p2 { (p1.clone(), p2.clone()) } else { (p2.clone(), p1.clone()) };
+                let count = teammates_map.entry(key.clone()).or_insert(0);
+                *count += 1;
+
+                if graph.find_edge(idx1, idx2).is_none() {
+                    graph.add_edge(idx1, idx2, 1);
+                } else {
+                    let edge = graph.find_edge(idx1, idx2).unwrap();
+                    let edge_weight = graph.edge_weight_mut(edge).unwrap();
+                    *edge_weight += 1;
+                }
+            }
+        }
+    }
+
+    graph
+}
+
+/// Simulates a bigger league by cloning the data into `copies` disjoint leagues
+fn enlarge(players: &[PlayerSeason], copies: usize) -> Vec<PlayerSeason> {
+    let mut out = Vec::with_capacity(players.len() * copies);
+    for copy in 0..copies {
+        for ps in players {
+            let mut ps = ps.clone();
+            ps.player_id = PlayerId::new(format!("{} [league {}]", ps.player_id, copy));
+            ps.team = format!("{}-{}", ps.team, copy);
+            out.push(ps);
+        }
+    }
+    out
+}
+
+/// Best wall-clock time over `runs` executions
+fn best_of<R>(runs: usize, mut f: impl FnMut() -> R) -> Duration {
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+fn main() {
+    let players = load_players("data/all_seasons.csv").expect("Cannot load data/all_seasons.csv");
+
+    for (label, data) in [("all_seasons.csv", players.clone()), ("8x enlarged", enlarge(&players, 8))] {
+        let legacy = best_of(5, || legacy_build(&data));
+        let interned = best_of(5, || build_player_graph(&data));
+        println!(
+            "{:<16} rows={:<6} legacy={:>10.2?} interned={:>10.2?} speedup={:.1}x",
+            label,
+            data.len(),
+            legacy,
+            interned,
+            legacy.as_secs_f64() / interned.as_secs_f64()
+        );
+    }
+}
@@ -2,116 +2,308 @@
 // Module: analysis
 // Purpose: Analyze structural properties of the player graph: degree distribution, centrality, path lengths, and similarity.
 
-use petgraph::algo::dijkstra;
 use std::collections::{HashMap, HashSet};
-use crate::graph_builder::PlayerGraph;
-use rand::prelude::*; // Brings .choose() method into scope
+use crate::data_loader::{PlayerId, Season};
+use crate::graph_builder::{BipartiteGraph, BipartiteNode, PlayerGraph};
+use petgraph::graph::NodeIndex;
+use rand::prelude::*; // Brings StdRng and .random_range() into scope
+
+mod betweenness;
+mod centrality;
+mod clustering;
+mod communities;
+mod components;
+mod cores;
+mod density;
+mod distances;
+mod paths;
+mod power_law;
+mod similarity;
+mod spectral;
+mod traversal;
+
+pub use betweenness::{compute_betweenness, BetweennessOptions};
+pub use centrality::{compute_centrality, compute_closeness, compute_weighted_centrality, compute_weighted_closeness, Closeness};
+pub use clustering::{compute_clustering, Clustering};
+pub use communities::{detect_communities, CommunityMethod, CommunityOptions, Partition};
+pub use components::{connected_components, giant_component, Components};
+pub use cores::{core_decomposition, CoreDecomposition};
+pub use density::{densest_subgraph, DenseSubgraph, DensestMethod};
+pub use distances::{compute_distance_stats, ComponentDistances, DistanceStats};
+pub use paths::{find_teammate_paths, shortest_teammate_path, PathHop, PathQuery, TeammatePath};
+pub use power_law::{fit_power_law, PowerLawFit, PowerLawOptions};
+pub use similarity::{most_similar_pairs, similar_players, MinHashOptions, SimilarPair, SimilarityMetric, SimilarityOptions};
+pub use spectral::{compute_eigenvector_centrality, compute_pagerank, EigenvectorOptions, PageRankOptions};
+
+/// Degree histogram plus headline degree figures
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DegreeDistribution {
+    /// degree -> number of players with that degree
+    pub counts: HashMap<usize, usize>,
+    pub mean_degree: f64,
+    pub max_degree: usize,
+}
 
 /// Returns a histogram of node degrees in the graph
-pub fn analyze_degrees(graph: &PlayerGraph) -> HashMap<usize, usize> {
+pub fn analyze_degrees(graph: &PlayerGraph) -> DegreeDistribution {
     let mut degree_count: HashMap<usize, usize> = HashMap::new();
+    let mut total = 0;
     // Count number of edges (degree) per node
     for node in graph.node_indices() {
         let degree = graph.edges(node).count();
+        total += degree;
         *degree_count.entry(degree).or_insert(0) += 1;
     }
-    degree_count
+    DegreeDistribution {
+        mean_degree: if graph.node_count() > 0 { total as f64 / graph.node_count() as f64 } else { 0.0 },
+        max_degree: degree_count.keys().copied().max().unwrap_or(0),
+        counts: degree_count,
+    }
 }
 
-/// Computes closeness centrality for each player
-/// Input: Graph reference
-/// Output: HashMap of player name to centrality score
-pub fn compute_centrality(graph: &PlayerGraph) -> HashMap<String, f64> {
-    let mut scores: HashMap<String, f64> = HashMap::new();
-    for node in graph.node_indices() {
-        // Dijkstra computes shortest paths from `node` to all others
-        let result = dijkstra(graph, node, None, |_| 1);
-        let total_distance: usize = result.values().sum();
-        // Closeness = (n-1) / sum of shortest distances
-        let closeness = if total_distance > 0 {
-            (result.len() - 1) as f64 / total_distance as f64
-        } else {
-            0.0
-        };
-        scores.insert(graph[node].clone(), closeness);
+/// Weighted degree (strength) of each player under the graph's EdgeWeighting
+/// e.g. total estimated games played alongside teammates for GamesOverlap
+pub fn analyze_strength(graph: &PlayerGraph) -> HashMap<PlayerId, f64> {
+    graph
+        .node_indices()
+        .map(|node| {
+            let strength: f64 = graph.edges(node).map(|e| e.weight().weight).sum();
+            (graph[node].clone(), strength)
+        })
+        .collect()
+}
+
+/// What to do with sampled pairs that have no connecting path
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DisconnectedPairs {
+    /// Count them separately and leave them out of the mean
+    #[default]
+    Report,
+    /// Draw replacements until `pairs` connected pairs are found
+    Reject,
+}
+
+/// Settings for estimating the average shortest path from random pairs
+#[derive(Debug, Clone)]
+pub struct PathSampling {
+    pub pairs: usize,
+    pub seed: u64,
+    pub disconnected: DisconnectedPairs,
+}
+
+impl Default for PathSampling {
+    fn default() -> Self {
+        PathSampling { pairs: 100, seed: 42, disconnected: DisconnectedPairs::Report }
     }
-    scores
 }
 
-/// Randomly samples 100 player pairs and computes average shortest path
-pub fn compute_shortest_paths(graph: &PlayerGraph) {
-    let nodes: Vec<_> = graph.node_indices().collect();
-    let mut rng = rand::thread_rng();
-    let mut sampled_pairs = Vec::new();
-
-    // Sample 100 unique random (a, b) pairs
-    while sampled_pairs.len() < 100 {
-        let a = *nodes.choose(&mut rng).unwrap();
-        let b = *nodes.choose(&mut rng).unwrap();
-        if a != b {
-            sampled_pairs.push((a, b));
-        }
+/// Result of estimating the average shortest path from random pairs
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PathSample {
+    pub pairs_sampled: usize,
+    pub connected_pairs: usize,
+    pub disconnected_pairs: usize,
+    /// Mean over connected pairs; None when no sampled pair was connected
+    pub average_length: Option<f64>,
+    /// Standard error of the mean (needs at least two connected pairs)
+    pub std_error: Option<f64>,
+    /// Normal-approximation 95% confidence interval for the mean
+    pub confidence_95: Option<(f64, f64)>,
+}
+
+/// Gives up on Reject mode after this many draws per requested pair,
+/// so a graph with almost no connected pairs cannot loop forever
+const MAX_DRAWS_PER_PAIR: usize = 100;
+
+/// Estimates the average shortest path from `sampling.pairs` random pairs.
+/// The RNG is seeded from `sampling.seed`, so repeated runs agree.
+pub fn compute_shortest_paths(graph: &PlayerGraph, sampling: &PathSampling) -> PathSample {
+    let n = graph.node_count();
+    if n < 2 || sampling.pairs == 0 {
+        return PathSample::default();
     }
+    let adj = traversal::adjacency(graph);
+    let mut rng = StdRng::seed_from_u64(sampling.seed);
+
+    let mut lengths: Vec<f64> = Vec::new();
+    let mut pairs_sampled = 0;
+    let mut disconnected_pairs = 0;
+    let max_draws = sampling.pairs * MAX_DRAWS_PER_PAIR;
 
-    let mut total_length = 0;
-    let mut count = 0;
-
-    for (a, b) in sampled_pairs {
-        // Use A* to find shortest path between nodes a and b
-        if let Some(path) = petgraph::algo::astar(
-            graph,
-            a,
-            |finish| finish == b,
-            |_| 1,
-            |_| 0,
-        ) {
-            total_length += path.0; // path.0 = total path cost (length)
-            count += 1;
+    let done = |sampled: usize, connected: usize| match sampling.disconnected {
+        DisconnectedPairs::Report => sampled >= sampling.pairs,
+        DisconnectedPairs::Reject => connected >= sampling.pairs || sampled >= max_draws,
+    };
+
+    while !done(pairs_sampled, lengths.len()) {
+        // Draw a uniformly random pair of distinct players
+        let a = rng.random_range(0..n);
+        let b = rng.random_range(0..n);
+        if a == b {
+            continue;
+        }
+        pairs_sampled += 1;
+        match traversal::bfs_distance(&adj, a, b) {
+            Some(d) => lengths.push(d as f64),
+            None => disconnected_pairs += 1,
         }
     }
 
-    // Print average if at least one valid path found
-    if count > 0 {
-        let avg_length = total_length as f64 / count as f64;
-        println!("Average shortest path over 100 sampled pairs: {:.4}", avg_length);
-    } else {
-        println!("No valid paths found in sample.");
+    let count = lengths.len();
+    let mean = (count > 0).then(|| lengths.iter().sum::<f64>() / count as f64);
+
+    // Sample standard deviation / sqrt(n), then mean ± 1.96 SE
+    let std_error = mean.filter(|_| count > 1).map(|m| {
+        let variance = lengths.iter().map(|l| (l - m).powi(2)).sum::<f64>() / (count - 1) as f64;
+        (variance / count as f64).sqrt()
+    });
+    let confidence_95 = mean.zip(std_error).map(|(m, se)| (m - 1.96 * se, m + 1.96 * se));
+
+    PathSample {
+        pairs_sampled,
+        connected_pairs: count,
+        disconnected_pairs,
+        average_length: mean,
+        std_error,
+        confidence_95,
     }
 }
 
-/// Finds the most structurally similar player pair using Jaccard similarity
-pub fn analyze_similarity(graph: &PlayerGraph) {
-    let mut max_sim = 0.0;
-    let mut most_similar = ("", "");
-
-    let nodes: Vec<_> = graph.node_indices().collect();
-    for i in 0..nodes.len() {
-        for j in i+1..nodes.len() {
-            let u = nodes[i];
-            let v = nodes[j];
-            let u_neighbors: HashSet<_> = graph.neighbors(u).collect();
-            let v_neighbors: HashSet<_> = graph.neighbors(v).collect();
-
-            // Compute Jaccard similarity = |A ∩ B| / |A ∪ B|
-            let intersection = u_neighbors.intersection(&v_neighbors).count();
-            let union = u_neighbors.union(&v_neighbors).count();
-
-            let jaccard = if union > 0 {
-                intersection as f64 / union as f64
-            } else {
-                0.0
-            };
-
-            // Track most similar pair
-            if jaccard > max_sim {
-                max_sim = jaccard;
-                most_similar = (&graph[u], &graph[v]);
-            }
+/// Finds the team-season node for `team` in `season`, if it exists
+pub fn find_team_season(bipartite: &BipartiteGraph, team: &str, season: Season) -> Option<NodeIndex> {
+    bipartite.node_indices().find(|&n| match &bipartite[n] {
+        BipartiteNode::TeamSeason { team: t, season: s, .. } => t == team && *s == season,
+        BipartiteNode::Player(_) => false,
+    })
+}
+
+/// Number of players appearing on both team-season rosters `a` and `b`
+pub fn team_season_overlap(bipartite: &BipartiteGraph, a: NodeIndex, b: NodeIndex) -> usize {
+    let roster_a: HashSet<NodeIndex> = bipartite.neighbors(a).collect();
+    bipartite.neighbors(b).collect::<HashSet<_>>().intersection(&roster_a).count()
+}
+
+/// Share of a roster that was on the same team the season before
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RosterContinuity {
+    pub team: String, // abbreviation played under that season
+    pub season: Season,
+    pub roster_size: usize,
+    pub returning: usize,
+    pub continuity: f64, // returning / roster_size
+}
+
+/// Computes roster continuity for every team-season that has a previous season
+/// Sorted by season, then team
+pub fn roster_continuity(bipartite: &BipartiteGraph) -> Vec<RosterContinuity> {
+    // Index team-season nodes so the previous season can be looked up directly
+    // Keyed by grouping key, so a relocated franchise still has a previous season
+    let mut rosters: HashMap<(&str, Season), (NodeIndex, &str)> = HashMap::new();
+    for node in bipartite.node_indices() {
+        if let BipartiteNode::TeamSeason { team, abbreviation, season } = &bipartite[node] {
+            rosters.insert((team.as_str(), *season), (node, abbreviation.as_str()));
         }
     }
 
-    println!(
-        "Most similar players: {} and {} (Jaccard similarity = {:.4})",
-        most_similar.0, most_similar.1, max_sim
-    );
+    let mut results = Vec::new();
+    for (&(team, season), &(node, abbreviation)) in &rosters {
+        let Some(&(previous, _)) = rosters.get(&(team, Season::new(season.start_year() - 1))) else {
+            continue;
+        };
+        let roster_size = bipartite.neighbors(node).collect::<HashSet<_>>().len();
+        let returning = team_season_overlap(bipartite, previous, node);
+        results.push(RosterContinuity {
+            team: abbreviation.to_string(),
+            season,
+            roster_size,
+            returning,
+            continuity: if roster_size > 0 { returning as f64 / roster_size as f64 } else { 0.0 },
+        });
+    }
+
+    results.sort_by(|a, b| a.season.cmp(&b.season).then_with(|| a.team.cmp(&b.team)));
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::{test_row, PlayerSeason, TeamGrouping};
+    use crate::graph_builder::{build_bipartite_graph, graph_from_edges, GraphOptions};
+
+    #[test]
+    fn degree_histogram_of_a_star() {
+        let degrees = analyze_degrees(&graph_from_edges(&[("hub", "x"), ("hub", "y"), ("hub", "z")]));
+        assert_eq!(degrees.counts, HashMap::from([(3, 1), (1, 3)]));
+        assert_eq!((degrees.mean_degree, degrees.max_degree), (1.5, 3));
+    }
+
+    #[test]
+    fn samples_path_lengths_and_reports_disconnected_pairs() {
+        // Path a - b - c - d and the separate pair x - y
+        let graph = graph_from_edges(&[("a", "b"), ("b", "c"), ("c", "d"), ("x", "y")]);
+        let report = compute_shortest_paths(&graph, &PathSampling { pairs: 200, ..PathSampling::default() });
+        assert_eq!(report.pairs_sampled, 200);
+        assert_eq!(report.connected_pairs + report.disconnected_pairs, 200);
+        assert!(report.disconnected_pairs > 0);
+        let mean = report.average_length.unwrap();
+        assert!((1.0..=3.0).contains(&mean));
+        let (lo, hi) = report.confidence_95.unwrap();
+        assert!(lo < mean && mean < hi);
+
+        let reject = PathSampling { pairs: 50, disconnected: DisconnectedPairs::Reject, ..PathSampling::default() };
+        let report = compute_shortest_paths(&graph, &reject);
+        // Rejected draws are still counted, but only connected pairs fill the quota
+        assert_eq!(report.connected_pairs, 50);
+        assert_eq!(report.pairs_sampled, 50 + report.disconnected_pairs);
+    }
+
+    #[test]
+    fn roster_continuity_follows_relocated_franchises() {
+        let rows: Vec<PlayerSeason> = vec![
+            test_row("Shareef Abdur-Rahim", "VAN", "2000-01"),
+            test_row("Mike Bibby", "VAN", "2000-01"),
+            test_row("Shareef Abdur-Rahim", "MEM", "2001-02"),
+            test_row("Pau Gasol", "MEM", "2001-02"),
+        ];
+        let grouped = |grouping| build_bipartite_graph(&rows, &GraphOptions { grouping, ..GraphOptions::default() });
+
+        let continuity = roster_continuity(&grouped(TeamGrouping::Franchise));
+        assert_eq!(continuity.len(), 1);
+        let memphis = &continuity[0];
+        assert_eq!((memphis.team.as_str(), memphis.season), ("MEM", Season::new(2001)));
+        assert_eq!((memphis.returning, memphis.roster_size, memphis.continuity), (1, 2, 0.5));
+
+        assert!(roster_continuity(&grouped(TeamGrouping::Abbreviation)).is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn results_serialize_with_plain_player_ids_and_seasons() {
+        let graph = graph_from_edges(&[("hub", "x"), ("hub", "y")]);
+        let json = serde_json::to_value(analyze_degrees(&graph)).unwrap();
+        assert_eq!(json["max_degree"], 2);
+
+        let stats = compute_distance_stats(&graph);
+        let json = serde_json::to_value(&stats).unwrap();
+        assert_eq!(json["eccentricity"]["hub"], 1);
+
+        let rows = vec![test_row("A", "VAN", "2000-01"), test_row("A", "MEM", "2001-02")];
+        let bipartite = build_bipartite_graph(&rows, &GraphOptions { grouping: TeamGrouping::Franchise, ..GraphOptions::default() });
+        let json = serde_json::to_value(roster_continuity(&bipartite)).unwrap();
+        assert_eq!(json[0]["season"], "2001-02");
+    }
+
+    #[test]
+    fn finds_team_seasons_by_grouping_key() {
+        let rows = vec![test_row("A", "NJN", "2005-06"), test_row("B", "NJN", "2005-06")];
+        let bipartite = build_bipartite_graph(&rows, &GraphOptions { grouping: TeamGrouping::Franchise, ..GraphOptions::default() });
+        let nets = find_team_season(&bipartite, "BKN", Season::new(2005)).unwrap();
+        assert!(matches!(&bipartite[nets], BipartiteNode::TeamSeason { abbreviation, .. } if abbreviation == "NJN"));
+        assert_eq!(team_season_overlap(&bipartite, nets, nets), 2);
+        assert!(find_team_season(&bipartite, "NJN", Season::new(2005)).is_none());
+    }
 }
@@ -0,0 +1,261 @@
+// src/analysis/betweenness.rs
+// Module: analysis::betweenness
+// Purpose: Betweenness centrality with Brandes' algorithm, exact or source-sampled, over hop counts or weighted edge distances.
+
+use super::traversal::{adjacency, parallel_map, weighted_adjacency, UNREACHED};
+use crate::data_loader::PlayerId;
+use crate::graph_builder::PlayerGraph;
+use rand::prelude::*;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+/// Settings for `compute_betweenness`
+#[derive(Debug, Clone)]
+pub struct BetweennessOptions {
+    /// Number of randomly chosen source players; None runs every source (exact)
+    pub samples: Option<usize>,
+    /// Seed for choosing sampled sources
+    pub seed: u64,
+    /// Measure paths by edge distance (1 / weight, i.e. inverse shared
+    /// stints under EdgeWeighting::SharedStints) instead of hop count
+    pub weighted: bool,
+    /// Divide by the number of pairs (n-1)(n-2)/2 so scores lie in [0, 1]
+    pub normalized: bool,
+}
+
+impl Default for BetweennessOptions {
+    fn default() -> Self {
+        BetweennessOptions { samples: None, seed: 42, weighted: false, normalized: true }
+    }
+}
+
+/// Two path lengths closer than this are treated as equal in weighted mode
+const TIE_EPSILON: f64 = 1e-9;
+
+/// Betweenness centrality of every player using Brandes' algorithm.
+/// High scores mark journeymen whose teammate links bridge parts of the
+/// league that would otherwise be far apart. Sampled mode runs Brandes from
+/// `samples` random sources and scales the result by n / samples.
+pub fn compute_betweenness(graph: &PlayerGraph, options: &BetweennessOptions) -> HashMap<PlayerId, f64> {
+    let n = graph.node_count();
+    let mut sources: Vec<usize> = (0..n).collect();
+    if let Some(k) = options.samples.filter(|&k| k < n) {
+        let mut rng = StdRng::seed_from_u64(options.seed);
+        sources.shuffle(&mut rng);
+        sources.truncate(k.max(1));
+    }
+
+    // One partial sum per worker keeps memory at O(threads * n)
+    let workers = std::thread::available_parallelism().map(|t| t.get()).unwrap_or(1);
+    let chunks: Vec<&[usize]> = sources.chunks(sources.len().div_ceil(workers).max(1)).collect();
+    let partials = if options.weighted {
+        let adj = weighted_adjacency(graph);
+        parallel_map(chunks.len(), |c| accumulate(n, chunks[c], |s, state| weighted_sssp(&adj, s, state)))
+    } else {
+        let adj = adjacency(graph);
+        parallel_map(chunks.len(), |c| accumulate(n, chunks[c], |s, state| unweighted_sssp(&adj, s, state)))
+    };
+
+    let mut scores = vec![0.0; n];
+    for partial in partials {
+        for (total, value) in scores.iter_mut().zip(partial) {
+            *total += value;
+        }
+    }
+
+    // Undirected paths are found from both ends; rescale sampled runs
+    let mut scale = 0.5;
+    if sources.len() < n && !sources.is_empty() {
+        scale *= n as f64 / sources.len() as f64;
+    }
+    if options.normalized && n > 2 {
+        scale /= ((n - 1) * (n - 2)) as f64 / 2.0;
+    }
+
+    graph
+        .node_indices()
+        .map(|node| (graph[node].clone(), scores[node.index()] * scale))
+        .collect()
+}
+
+/// Per-source working state reused across sources
+struct SourceState {
+    order: Vec<usize>,      // nodes in non-decreasing distance order
+    preds: Vec<Vec<usize>>, // shortest-path predecessors
+    sigma: Vec<f64>,        // number of shortest paths from the source
+    delta: Vec<f64>,        // dependency accumulated in the back-propagation
+}
+
+/// Runs single-source shortest paths from each source and back-propagates
+/// dependencies (Brandes' accumulation step)
+fn accumulate(n: usize, sources: &[usize], sssp: impl Fn(usize, &mut SourceState)) -> Vec<f64> {
+    let mut scores = vec![0.0; n];
+    let mut state = SourceState {
+        order: Vec::with_capacity(n),
+        preds: vec![Vec::new(); n],
+        sigma: vec![0.0; n],
+        delta: vec![0.0; n],
+    };
+
+    for &s in sources {
+        for v in 0..n {
+            state.preds[v].clear();
+            state.sigma[v] = 0.0;
+            state.delta[v] = 0.0;
+        }
+        state.order.clear();
+        sssp(s, &mut state);
+
+        // Walk nodes from farthest to nearest
+        for &w in state.order.iter().rev() {
+            let coeff = (1.0 + state.delta[w]) / state.sigma[w];
+            for i in 0..state.preds[w].len() {
+                let v = state.preds[w][i];
+                state.delta[v] += state.sigma[v] * coeff;
+            }
+            if w != s {
+                scores[w] += state.delta[w];
+            }
+        }
+    }
+    scores
+}
+
+/// BFS shortest paths by hop count
+fn unweighted_sssp(adj: &[Vec<usize>], s: usize, state: &mut SourceState) {
+    let mut dist = vec![UNREACHED; adj.len()];
+    dist[s] = 0;
+    state.sigma[s] = 1.0;
+    state.order.push(s);
+
+    let mut head = 0;
+    while head < state.order.len() {
+        let v = state.order[head];
+        head += 1;
+        for &w in &adj[v] {
+            if dist[w] == UNREACHED {
+                dist[w] = dist[v] + 1;
+                state.order.push(w);
+            }
+            if dist[w] == dist[v] + 1 {
+                state.sigma[w] += state.sigma[v];
+                state.preds[w].push(v);
+            }
+        }
+    }
+}
+
+/// Min-heap entry for Dijkstra
+struct Candidate {
+    dist: f64,
+    node: usize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    // Reversed so BinaryHeap pops the shortest distance first
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.dist.total_cmp(&self.dist).then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+/// Dijkstra shortest paths by edge distance
+fn weighted_sssp(adj: &[Vec<(usize, f64)>], s: usize, state: &mut SourceState) {
+    let mut dist = vec![f64::INFINITY; adj.len()];
+    let mut settled = vec![false; adj.len()];
+    let mut heap = BinaryHeap::new();
+    dist[s] = 0.0;
+    state.sigma[s] = 1.0;
+    heap.push(Candidate { dist: 0.0, node: s });
+
+    while let Some(Candidate { dist: d, node: v }) = heap.pop() {
+        if settled[v] {
+            continue;
+        }
+        settled[v] = true;
+        state.order.push(v);
+
+        for &(w, length) in &adj[v] {
+            let candidate = d + length;
+            if candidate < dist[w] - TIE_EPSILON {
+                // Strictly shorter: forget earlier predecessors
+                dist[w] = candidate;
+                state.sigma[w] = state.sigma[v];
+                state.preds[w].clear();
+                state.preds[w].push(v);
+                heap.push(Candidate { dist: candidate, node: w });
+            } else if (candidate - dist[w]).abs() <= TIE_EPSILON && !settled[w] {
+                state.sigma[w] += state.sigma[v];
+                state.preds[w].push(v);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_builder::{graph_from_edges, weighted_graph_from_edges};
+
+    fn score(scores: &HashMap<PlayerId, f64>, name: &str) -> f64 {
+        scores[&PlayerId::new(name)]
+    }
+
+    fn raw() -> BetweennessOptions {
+        BetweennessOptions { normalized: false, ..BetweennessOptions::default() }
+    }
+
+    #[test]
+    fn counts_pairs_routed_through_each_player() {
+        // a - b - c - d: b and c each sit on 2 of the 6 shortest paths
+        let path = graph_from_edges(&[("a", "b"), ("b", "c"), ("c", "d")]);
+        let scores = compute_betweenness(&path, &raw());
+        assert_eq!([score(&scores, "a"), score(&scores, "b"), score(&scores, "c"), score(&scores, "d")], [0.0, 2.0, 2.0, 0.0]);
+
+        let normalized = compute_betweenness(&path, &BetweennessOptions::default());
+        assert!((score(&normalized, "b") - 2.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn splits_credit_across_tied_shortest_paths() {
+        // Square a - b - c - d - a: each of a→c and b→d has two shortest paths
+        let square = graph_from_edges(&[("a", "b"), ("b", "c"), ("c", "d"), ("d", "a")]);
+        let scores = compute_betweenness(&square, &raw());
+        for name in ["a", "b", "c", "d"] {
+            assert!((score(&scores, name) - 0.5).abs() < 1e-12);
+        }
+
+        let star = graph_from_edges(&[("hub", "x"), ("hub", "y"), ("hub", "z"), ("hub", "w")]);
+        assert_eq!(score(&compute_betweenness(&star, &raw()), "hub"), 6.0);
+    }
+
+    #[test]
+    fn weighted_mode_follows_strong_ties() {
+        // The direct a - c tie is weak (distance 10), so a→c runs through b
+        let graph = weighted_graph_from_edges(&[("a", "b", 1.0), ("b", "c", 1.0), ("a", "c", 0.1)]);
+        assert_eq!(score(&compute_betweenness(&graph, &raw()), "b"), 0.0);
+        let weighted = compute_betweenness(&graph, &BetweennessOptions { weighted: true, ..raw() });
+        assert_eq!(score(&weighted, "b"), 1.0);
+    }
+
+    #[test]
+    fn sampling_every_source_matches_exact() {
+        let graph = graph_from_edges(&[("a", "b"), ("b", "c"), ("c", "d"), ("b", "e"), ("e", "d"), ("d", "f")]);
+        let exact = compute_betweenness(&graph, &raw());
+        let sampled = compute_betweenness(&graph, &BetweennessOptions { samples: Some(100), ..raw() });
+        assert_eq!(exact, sampled);
+    }
+}
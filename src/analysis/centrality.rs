@@ -0,0 +1,143 @@
+// src/analysis/centrality.rs
+// Module: analysis::centrality
+// Purpose: Closeness centrality for every player via multi-threaded BFS or Dijkstra, with Wasserman-Faust and harmonic variants for disconnected graphs.
+
+use super::traversal::{adjacency, bfs, dijkstra, parallel_map, weighted_adjacency};
+use crate::data_loader::PlayerId;
+use crate::graph_builder::PlayerGraph;
+use std::collections::HashMap;
+
+/// Closeness definition used by `compute_closeness`.
+/// With r nodes reachable from v (including v) out of n in total:
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Closeness {
+    /// (r-1) / sum of distances, normalized over the reachable set only.
+    /// Inflates scores for players in small components.
+    Classic,
+    /// Classic scaled by (r-1)/(n-1), so a perfect score requires
+    /// reaching the whole graph
+    #[default]
+    WassermanFaust,
+    /// Sum of 1/distance over all other nodes, divided by (n-1);
+    /// unreachable nodes simply contribute 0
+    Harmonic,
+}
+
+/// Computes closeness centrality for each player
+/// Input: Graph reference
+/// Output: HashMap of player id to centrality score (Wasserman-Faust closeness)
+pub fn compute_centrality(graph: &PlayerGraph) -> HashMap<PlayerId, f64> {
+    compute_closeness(graph, Closeness::default())
+}
+
+/// Closeness centrality by unweighted BFS from every player, spread across
+/// all cores. Hop counts only; see `compute_weighted_closeness` for weights.
+pub fn compute_closeness(graph: &PlayerGraph, variant: Closeness) -> HashMap<PlayerId, f64> {
+    let adj = adjacency(graph);
+    let n = adj.len();
+
+    let scores = parallel_map(n, |source| {
+        let mut dist = Vec::new();
+        let reached = bfs(&adj, source, &mut dist);
+        closeness_score(variant, n, reached[1..].iter().map(|&v| dist[v] as f64))
+    });
+    keyed_by_player(graph, scores)
+}
+
+/// Wasserman-Faust closeness using edge weights, see `compute_weighted_closeness`
+pub fn compute_weighted_centrality(graph: &PlayerGraph) -> HashMap<PlayerId, f64> {
+    compute_weighted_closeness(graph, Closeness::default())
+}
+
+/// Closeness centrality using edge weights: a strong tie is a short hop
+/// (distance = 1 / weight), so players with many long-running partnerships
+/// score higher than ones with many brief stints. Runs Dijkstra from every
+/// player across all cores.
+pub fn compute_weighted_closeness(graph: &PlayerGraph, variant: Closeness) -> HashMap<PlayerId, f64> {
+    let adj = weighted_adjacency(graph);
+    let n = adj.len();
+
+    let scores = parallel_map(n, |source| {
+        let mut dist = Vec::new();
+        let reached = dijkstra(&adj, source, &mut dist);
+        closeness_score(variant, n, reached[1..].iter().map(|&v| dist[v]))
+    });
+    keyed_by_player(graph, scores)
+}
+
+/// Closeness of one source out of `n` players, from its distances to every
+/// other player it reaches
+fn closeness_score(variant: Closeness, n: usize, distances: impl Iterator<Item = f64>) -> f64 {
+    let (mut others, mut total, mut inverse) = (0usize, 0.0, 0.0);
+    for d in distances {
+        others += 1;
+        total += d;
+        inverse += 1.0 / d;
+    }
+
+    match variant {
+        Closeness::Classic | Closeness::WassermanFaust => {
+            if total == 0.0 {
+                return 0.0;
+            }
+            let classic = others as f64 / total;
+            if variant == Closeness::Classic {
+                classic
+            } else {
+                classic * others as f64 / (n - 1) as f64
+            }
+        }
+        Closeness::Harmonic => {
+            if n > 1 { inverse / (n - 1) as f64 } else { 0.0 }
+        }
+    }
+}
+
+/// Pairs per-node scores (indexed by node index) with player ids
+fn keyed_by_player(graph: &PlayerGraph, scores: Vec<f64>) -> HashMap<PlayerId, f64> {
+    graph
+        .node_indices()
+        .map(|node| (graph[node].clone(), scores[node.index()]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_builder::{graph_from_edges, weighted_graph_from_edges};
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-12
+    }
+
+    #[test]
+    fn closeness_variants_on_a_path_and_a_loner() {
+        // a - b - c plus an isolated player: n = 4
+        let mut graph = graph_from_edges(&[("a", "b"), ("b", "c")]);
+        graph.add_node(PlayerId::new("loner"));
+        let score = |variant, name: &str| compute_closeness(&graph, variant)[&PlayerId::new(name)];
+
+        assert!(close(score(Closeness::Classic, "b"), 1.0));
+        assert!(close(score(Closeness::Classic, "a"), 2.0 / 3.0));
+        assert!(close(score(Closeness::WassermanFaust, "b"), 2.0 / 3.0));
+        assert!(close(score(Closeness::WassermanFaust, "a"), 4.0 / 9.0));
+        assert!(close(score(Closeness::Harmonic, "a"), 1.5 / 3.0));
+        assert_eq!(score(Closeness::WassermanFaust, "loner"), 0.0);
+        assert_eq!(compute_centrality(&graph), compute_closeness(&graph, Closeness::WassermanFaust));
+    }
+
+    #[test]
+    fn weighted_closeness_treats_strong_ties_as_short() {
+        // Distances 1/4 and 1/2, plus an isolated player: n = 4
+        let mut graph = weighted_graph_from_edges(&[("a", "b", 4.0), ("b", "c", 2.0)]);
+        graph.add_node(PlayerId::new("loner"));
+        let score = |variant, name: &str| compute_weighted_closeness(&graph, variant)[&PlayerId::new(name)];
+
+        assert!(close(score(Closeness::Classic, "b"), 2.0 / 0.75));
+        assert!(close(score(Closeness::Classic, "a"), 2.0 / 1.0));
+        assert!(close(score(Closeness::WassermanFaust, "b"), 2.0 / 0.75 * 2.0 / 3.0));
+        assert!(close(score(Closeness::Harmonic, "a"), (4.0 + 1.0 / 0.75) / 3.0));
+        assert_eq!(score(Closeness::WassermanFaust, "loner"), 0.0);
+        assert_eq!(compute_weighted_centrality(&graph), compute_weighted_closeness(&graph, Closeness::WassermanFaust));
+    }
+}
@@ -0,0 +1,120 @@
+// src/analysis/clustering.rs
+// Module: analysis::clustering
+// Purpose: Triangle counting, local clustering coefficients, average clustering and global transitivity.
+
+use super::traversal::adjacency;
+use crate::data_loader::PlayerId;
+use crate::graph_builder::PlayerGraph;
+use std::collections::HashMap;
+
+/// Clustering statistics of the player graph
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Clustering {
+    /// Share of a player's teammate pairs who were also teammates (0 below degree 2)
+    pub local: HashMap<PlayerId, f64>,
+    /// Triangles each player belongs to
+    pub triangles: HashMap<PlayerId, usize>,
+    pub total_triangles: usize,
+    /// 3 * triangles / connected triples
+    pub transitivity: f64,
+    /// Mean local clustering over all players
+    pub average_clustering: f64,
+    /// Expected clustering of a random graph with the same size and density, mean degree / (n - 1)
+    pub random_expectation: f64,
+    /// (degree, local clustering) per player, for plotting
+    pub degree_vs_clustering: Vec<(usize, f64)>,
+}
+
+/// Counts triangles by orienting each edge from lower to higher
+/// (degree, index) rank, so every triangle is found once and no node has
+/// more than O(sqrt(m)) out-neighbors: O(m^1.5) overall.
+pub fn compute_clustering(graph: &PlayerGraph) -> Clustering {
+    let mut adj = adjacency(graph);
+    adj.iter_mut().for_each(|nbrs| {
+        nbrs.sort_unstable();
+        nbrs.dedup();
+    });
+    let n = adj.len();
+    let degree: Vec<usize> = adj.iter().map(|nbrs| nbrs.len()).collect();
+    let rank = |v: usize| (degree[v], v);
+    let forward: Vec<Vec<usize>> = (0..n)
+        .map(|u| adj[u].iter().copied().filter(|&v| rank(v) > rank(u)).collect())
+        .collect();
+
+    let mut triangles = vec![0usize; n];
+    let mut marked = vec![false; n];
+    for u in 0..n {
+        forward[u].iter().for_each(|&v| marked[v] = true);
+        for &v in &forward[u] {
+            for &w in &forward[v] {
+                if marked[w] {
+                    triangles[u] += 1;
+                    triangles[v] += 1;
+                    triangles[w] += 1;
+                }
+            }
+        }
+        forward[u].iter().for_each(|&v| marked[v] = false);
+    }
+
+    let local: Vec<f64> = (0..n)
+        .map(|v| {
+            let d = degree[v];
+            if d < 2 { 0.0 } else { 2.0 * triangles[v] as f64 / (d * (d - 1)) as f64 }
+        })
+        .collect();
+    let total_triangles = triangles.iter().sum::<usize>() / 3;
+    let triples: usize = degree.iter().map(|&d| d * d.saturating_sub(1) / 2).sum();
+    let transitivity = if triples == 0 { 0.0 } else { 3.0 * total_triangles as f64 / triples as f64 };
+    let average_clustering = if n == 0 { 0.0 } else { local.iter().sum::<f64>() / n as f64 };
+    let mean_degree = if n == 0 { 0.0 } else { degree.iter().sum::<usize>() as f64 / n as f64 };
+    let random_expectation = if n > 1 { mean_degree / (n - 1) as f64 } else { 0.0 };
+
+    let ids = || graph.node_indices().map(|node| graph[node].clone());
+    Clustering {
+        local: ids().zip(local.iter().copied()).collect(),
+        triangles: ids().zip(triangles.iter().copied()).collect(),
+        total_triangles,
+        transitivity,
+        average_clustering,
+        random_expectation,
+        degree_vs_clustering: degree.into_iter().zip(local).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_builder::graph_from_edges;
+
+    #[test]
+    fn triangle_with_a_pendant() {
+        let graph = graph_from_edges(&[("a", "b"), ("b", "c"), ("c", "a"), ("c", "d")]);
+        let clustering = compute_clustering(&graph);
+        let local = |name: &str| clustering.local[&PlayerId::new(name)];
+
+        assert_eq!(clustering.total_triangles, 1);
+        assert_eq!(clustering.triangles[&PlayerId::new("c")], 1);
+        assert_eq!(clustering.triangles[&PlayerId::new("d")], 0);
+        assert_eq!([local("a"), local("b"), local("d")], [1.0, 1.0, 0.0]);
+        assert!((local("c") - 1.0 / 3.0).abs() < 1e-12);
+        // 3 * 1 triangle / (1 + 1 + 3 + 0) connected triples
+        assert!((clustering.transitivity - 0.6).abs() < 1e-12);
+        assert!((clustering.average_clustering - (7.0 / 3.0) / 4.0).abs() < 1e-12);
+        // Mean degree 2 over n - 1 = 3 possible teammates
+        assert!((clustering.random_expectation - 2.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn counts_every_triangle_of_a_clique_once() {
+        let names = ["a", "b", "c", "d", "e"];
+        let edges: Vec<(&str, &str)> =
+            (0..5).flat_map(|i| (i + 1..5).map(move |j| (names[i], names[j]))).collect();
+        let clustering = compute_clustering(&graph_from_edges(&edges));
+        // C(5, 3) triangles, each player in C(4, 2) of them
+        assert_eq!(clustering.total_triangles, 10);
+        assert!(clustering.triangles.values().all(|&t| t == 6));
+        assert_eq!((clustering.transitivity, clustering.average_clustering), (1.0, 1.0));
+    }
+}
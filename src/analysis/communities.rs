@@ -0,0 +1,360 @@
+// src/analysis/communities.rs
+// Module: analysis::communities
+// Purpose: Community detection on the weighted teammate graph (Louvain and label propagation) scored by modularity.
+
+use super::traversal::strength_adjacency;
+use crate::data_loader::PlayerId;
+use crate::graph_builder::PlayerGraph;
+use petgraph::graph::NodeIndex;
+use rand::prelude::*;
+use std::collections::HashMap;
+
+/// Community detection algorithm
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CommunityMethod {
+    /// Greedy modularity optimization with graph aggregation (Blondel et al.)
+    #[default]
+    Louvain,
+    /// Players repeatedly adopt the label with the most tie weight among their teammates.
+    /// Fast, but on dense graphs one label tends to flood most of the graph; check its
+    /// modularity against Louvain's.
+    LabelPropagation,
+}
+
+/// Settings for `detect_communities`
+#[derive(Debug, Clone)]
+pub struct CommunityOptions {
+    pub method: CommunityMethod,
+    /// Modularity resolution: above 1 favors smaller communities, below 1 larger ones
+    pub resolution: f64,
+    /// Seed for the node visiting order (and label propagation tie-breaks)
+    pub seed: u64,
+    /// Cap on label propagation rounds
+    pub max_iterations: usize,
+}
+
+impl Default for CommunityOptions {
+    fn default() -> Self {
+        CommunityOptions { method: CommunityMethod::Louvain, resolution: 1.0, seed: 42, max_iterations: 100 }
+    }
+}
+
+/// A split of the players into disjoint communities
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Partition {
+    /// Communities from largest to smallest; members ordered by tie strength, strongest first
+    pub communities: Vec<Vec<PlayerId>>,
+    /// Player -> index into `communities`
+    pub membership: HashMap<PlayerId, usize>,
+    /// Weighted modularity of the split at the chosen resolution
+    pub modularity: f64,
+}
+
+/// Two modularity gains closer than this count as equal
+const GAIN_EPSILON: f64 = 1e-12;
+
+/// Splits the players into communities using edge weights as tie strength.
+/// On the teammate graph these tend to be franchise eras and long-running cores.
+pub fn detect_communities(graph: &PlayerGraph, options: &CommunityOptions) -> Partition {
+    let adj = strength_adjacency(graph);
+    let mut rng = StdRng::seed_from_u64(options.seed);
+    let labels = match options.method {
+        CommunityMethod::Louvain => louvain(&adj, options.resolution, &mut rng),
+        CommunityMethod::LabelPropagation => label_propagation(&adj, options.max_iterations, &mut rng),
+    };
+    build_partition(graph, &adj, &labels, options.resolution)
+}
+
+/// Working graph of one Louvain level; node i may stand for a whole community below
+struct Level {
+    adj: Vec<Vec<(usize, f64)>>,
+    self_loops: Vec<f64>, // internal weight of each node, counted once
+    degree: Vec<f64>,     // weighted degree, self-loops counted twice
+}
+
+impl Level {
+    fn new(adj: Vec<Vec<(usize, f64)>>, self_loops: Vec<f64>) -> Self {
+        let degree = adj
+            .iter()
+            .zip(&self_loops)
+            .map(|(nbrs, &own)| nbrs.iter().map(|(_, w)| w).sum::<f64>() + 2.0 * own)
+            .collect();
+        Level { adj, self_loops, degree }
+    }
+}
+
+/// Louvain: move nodes between neighboring communities while modularity
+/// improves, collapse each community into one node, and repeat until a
+/// level makes no move. Returns a community label per original node.
+fn louvain(adj: &[Vec<(usize, f64)>], resolution: f64, rng: &mut StdRng) -> Vec<usize> {
+    let n = adj.len();
+    let mut level = Level::new(adj.to_vec(), vec![0.0; n]);
+    let total: f64 = level.degree.iter().sum();
+    let mut labels: Vec<usize> = (0..n).collect();
+    if total <= 0.0 {
+        return labels;
+    }
+
+    while let Some((community, count)) = move_nodes(&level, total, resolution, rng) {
+        for label in labels.iter_mut() {
+            *label = community[*label];
+        }
+        level = aggregate(&level, &community, count);
+    }
+    labels
+}
+
+/// One round of local moves on `level`.
+/// Returns each node's community (renumbered 0..count) and the count, or
+/// None if no node moved.
+fn move_nodes(level: &Level, total: f64, resolution: f64, rng: &mut StdRng) -> Option<(Vec<usize>, usize)> {
+    let n = level.adj.len();
+    let mut community: Vec<usize> = (0..n).collect();
+    let mut community_degree = level.degree.clone();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.shuffle(rng);
+
+    // Tie weight from the current node to each neighboring community
+    let mut link = vec![0.0; n];
+    let mut touched = vec![false; n];
+    let mut neighbors: Vec<usize> = Vec::new();
+
+    let mut moved = false;
+    loop {
+        let mut moves = 0;
+        for &i in &order {
+            let current = community[i];
+            let k = level.degree[i];
+            for &(j, w) in &level.adj[i] {
+                let c = community[j];
+                if !touched[c] {
+                    touched[c] = true;
+                    neighbors.push(c);
+                }
+                link[c] += w;
+            }
+
+            // Take i out, then put it back wherever the gain is largest
+            community_degree[current] -= k;
+            let gain = |c: usize| link[c] - resolution * community_degree[c] * k / total;
+            let mut best = current;
+            let mut best_gain = gain(current);
+            for &c in &neighbors {
+                let g = gain(c);
+                if g > best_gain + GAIN_EPSILON {
+                    best = c;
+                    best_gain = g;
+                }
+            }
+            community_degree[best] += k;
+            if best != current {
+                community[i] = best;
+                moves += 1;
+            }
+
+            for c in neighbors.drain(..) {
+                touched[c] = false;
+                link[c] = 0.0;
+            }
+        }
+        if moves == 0 {
+            break;
+        }
+        moved = true;
+    }
+
+    if !moved {
+        return None;
+    }
+    let count = renumber(&mut community);
+    Some((community, count))
+}
+
+/// Collapses every community of `level` into a single node
+fn aggregate(level: &Level, community: &[usize], count: usize) -> Level {
+    let mut links: Vec<HashMap<usize, f64>> = vec![HashMap::new(); count];
+    let mut self_loops = vec![0.0; count];
+    for (i, nbrs) in level.adj.iter().enumerate() {
+        let a = community[i];
+        self_loops[a] += level.self_loops[i];
+        for &(j, w) in nbrs {
+            let b = community[j];
+            if a == b {
+                // Seen from both endpoints
+                self_loops[a] += w / 2.0;
+            } else {
+                *links[a].entry(b).or_insert(0.0) += w;
+            }
+        }
+    }
+
+    let adj = links
+        .into_iter()
+        .map(|l| {
+            let mut nbrs: Vec<(usize, f64)> = l.into_iter().collect();
+            nbrs.sort_unstable_by_key(|&(j, _)| j);
+            nbrs
+        })
+        .collect();
+    Level::new(adj, self_loops)
+}
+
+/// Asynchronous label propagation: in random order, each player takes the
+/// label carrying the most tie weight among their teammates (keeping their
+/// own on a tie) until no label changes.
+fn label_propagation(adj: &[Vec<(usize, f64)>], max_iterations: usize, rng: &mut StdRng) -> Vec<usize> {
+    let n = adj.len();
+    let mut labels: Vec<usize> = (0..n).collect();
+    let mut order: Vec<usize> = (0..n).collect();
+    let mut weight = vec![0.0; n];
+    let mut touched = vec![false; n];
+    let mut candidates: Vec<usize> = Vec::new();
+
+    for _ in 0..max_iterations {
+        order.shuffle(rng);
+        let mut changed = false;
+        for &i in &order {
+            if adj[i].is_empty() {
+                continue;
+            }
+            for &(j, w) in &adj[i] {
+                if !touched[labels[j]] {
+                    touched[labels[j]] = true;
+                    candidates.push(labels[j]);
+                }
+                weight[labels[j]] += w;
+            }
+            let best = candidates.iter().map(|&l| weight[l]).fold(f64::MIN, f64::max);
+            let tied: Vec<usize> = candidates.iter().copied().filter(|&l| weight[l] >= best - GAIN_EPSILON).collect();
+            if !tied.contains(&labels[i]) {
+                labels[i] = tied[rng.random_range(0..tied.len())];
+                changed = true;
+            }
+            for l in candidates.drain(..) {
+                touched[l] = false;
+                weight[l] = 0.0;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    labels
+}
+
+/// Renumbers labels to 0..count in order of first appearance; returns count
+fn renumber(labels: &mut [usize]) -> usize {
+    let mut ids: HashMap<usize, usize> = HashMap::new();
+    for label in labels.iter_mut() {
+        let next = ids.len();
+        *label = *ids.entry(*label).or_insert(next);
+    }
+    ids.len()
+}
+
+/// Weighted modularity: sum over communities of
+/// internal / m - resolution * (degree / 2m)^2
+fn modularity(adj: &[Vec<(usize, f64)>], labels: &[usize], count: usize, resolution: f64) -> f64 {
+    let mut internal = vec![0.0; count];
+    let mut degree = vec![0.0; count];
+    for (i, nbrs) in adj.iter().enumerate() {
+        for &(j, w) in nbrs {
+            degree[labels[i]] += w;
+            if labels[i] == labels[j] {
+                internal[labels[i]] += w / 2.0;
+            }
+        }
+    }
+    let total: f64 = degree.iter().sum();
+    if total <= 0.0 {
+        return 0.0;
+    }
+    let m = total / 2.0;
+    internal
+        .iter()
+        .zip(&degree)
+        .map(|(&inside, &d)| inside / m - resolution * (d / total).powi(2))
+        .sum()
+}
+
+/// Groups players by label, largest community first, and scores the split
+fn build_partition(graph: &PlayerGraph, adj: &[Vec<(usize, f64)>], labels: &[usize], resolution: f64) -> Partition {
+    let mut labels = labels.to_vec();
+    let count = renumber(&mut labels);
+    let strength: Vec<f64> = adj.iter().map(|nbrs| nbrs.iter().map(|(_, w)| w).sum()).collect();
+
+    let mut groups: Vec<Vec<usize>> = vec![Vec::new(); count];
+    for (node, &label) in labels.iter().enumerate() {
+        groups[label].push(node);
+    }
+    for group in groups.iter_mut() {
+        group.sort_by(|&a, &b| strength[b].total_cmp(&strength[a]).then(a.cmp(&b)));
+    }
+    groups.sort_by(|a, b| b.len().cmp(&a.len()).then(a[0].cmp(&b[0])));
+
+    let communities: Vec<Vec<PlayerId>> = groups
+        .iter()
+        .map(|group| group.iter().map(|&node| graph[NodeIndex::new(node)].clone()).collect())
+        .collect();
+    let membership = communities
+        .iter()
+        .enumerate()
+        .flat_map(|(c, members)| members.iter().map(move |id| (id.clone(), c)))
+        .collect();
+
+    Partition { communities, membership, modularity: modularity(adj, &labels, count, resolution) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_builder::{graph_from_edges, weighted_graph_from_edges};
+
+    /// Two 4-cliques, a..d and w..z, joined by the single edge d - w
+    fn two_cliques() -> PlayerGraph {
+        let clique = |names: [&'static str; 4]| {
+            (0..4).flat_map(move |i| (i + 1..4).map(move |j| (names[i], names[j])))
+        };
+        let mut edges: Vec<(&str, &str)> = clique(["a", "b", "c", "d"]).chain(clique(["w", "x", "y", "z"])).collect();
+        edges.push(("d", "w"));
+        graph_from_edges(&edges)
+    }
+
+    fn community_of(partition: &Partition, name: &str) -> usize {
+        partition.membership[&PlayerId::new(name)]
+    }
+
+    #[test]
+    fn louvain_separates_two_cliques() {
+        let partition = detect_communities(&two_cliques(), &CommunityOptions::default());
+        assert_eq!(partition.communities.len(), 2);
+        assert!(["b", "c", "d"].iter().all(|n| community_of(&partition, n) == community_of(&partition, "a")));
+        assert!(["x", "y", "z"].iter().all(|n| community_of(&partition, n) == community_of(&partition, "w")));
+        assert_ne!(community_of(&partition, "a"), community_of(&partition, "w"));
+        // Each side holds 6 of the 13 edges and half of the total degree
+        assert!((partition.modularity - (12.0 / 13.0 - 0.5)).abs() < 1e-12);
+        // Bridge endpoints have the most tie strength and lead their communities
+        assert!(partition.communities.iter().all(|c| c[0].as_str() == "d" || c[0].as_str() == "w"));
+    }
+
+    #[test]
+    fn label_propagation_keeps_components_apart() {
+        let graph = graph_from_edges(&[("a", "b"), ("b", "c"), ("c", "a"), ("x", "y"), ("y", "z"), ("z", "x")]);
+        let options = CommunityOptions { method: CommunityMethod::LabelPropagation, ..CommunityOptions::default() };
+        let partition = detect_communities(&graph, &options);
+        assert_eq!(partition.communities.len(), 2);
+        assert_ne!(community_of(&partition, "a"), community_of(&partition, "x"));
+        assert!((partition.modularity - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn modularity_of_fixed_splits() {
+        let adj = strength_adjacency(&weighted_graph_from_edges(&[("a", "b", 2.0), ("c", "d", 1.0), ("b", "c", 1.0)]));
+        // Everyone together scores 0
+        assert!(modularity(&adj, &[0, 0, 0, 0], 1, 1.0).abs() < 1e-12);
+        // {a, b} and {c, d}: internal weight 3 of 4; strengths 2 + 3 and 2 + 1 out of 8
+        let expected = 3.0 / 4.0 - ((5.0f64 / 8.0).powi(2) + (3.0f64 / 8.0).powi(2));
+        assert!((modularity(&adj, &[0, 0, 1, 1], 2, 1.0) - expected).abs() < 1e-12);
+    }
+}
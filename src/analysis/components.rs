@@ -0,0 +1,99 @@
+// src/analysis/components.rs
+// Module: analysis::components
+// Purpose: Connected component labeling, component size distribution, and giant component extraction.
+
+use super::traversal::{adjacency, component_labels};
+use crate::data_loader::PlayerId;
+use crate::graph_builder::PlayerGraph;
+use std::collections::{BTreeMap, HashMap};
+
+/// Connected components of the player graph
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Components {
+    /// Player -> component id; 0 is the largest (giant) component
+    pub labels: HashMap<PlayerId, usize>,
+    /// Component sizes indexed by id, so largest first
+    pub sizes: Vec<usize>,
+}
+
+impl Components {
+    /// Number of connected components
+    pub fn count(&self) -> usize {
+        self.sizes.len()
+    }
+
+    /// Players in the giant component (0 for an empty graph)
+    pub fn giant_size(&self) -> usize {
+        self.sizes.first().copied().unwrap_or(0)
+    }
+
+    /// Share of all players inside the giant component
+    pub fn giant_fraction(&self) -> f64 {
+        let total: usize = self.sizes.iter().sum();
+        if total == 0 { 0.0 } else { self.giant_size() as f64 / total as f64 }
+    }
+
+    /// component size -> number of components of that size
+    pub fn size_distribution(&self) -> BTreeMap<usize, usize> {
+        let mut distribution = BTreeMap::new();
+        for &size in &self.sizes {
+            *distribution.entry(size).or_insert(0) += 1;
+        }
+        distribution
+    }
+}
+
+/// Labels every player with their connected component
+pub fn connected_components(graph: &PlayerGraph) -> Components {
+    let labels = component_labels(&adjacency(graph));
+    let mut sizes = vec![0; labels.iter().map(|&l| l + 1).max().unwrap_or(0)];
+    for &label in &labels {
+        sizes[label] += 1;
+    }
+    let labels = graph.node_indices().map(|n| (graph[n].clone(), labels[n.index()])).collect();
+    Components { labels, sizes }
+}
+
+/// Copy of the largest connected component as its own graph.
+/// Players keep their relative order and edges keep their stints and weights.
+pub fn giant_component(graph: &PlayerGraph) -> PlayerGraph {
+    let labels = component_labels(&adjacency(graph));
+    graph.filter_map(
+        |n, id| (labels[n.index()] == 0).then(|| id.clone()),
+        |_, edge| Some(edge.clone()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_builder::graph_from_edges;
+
+    fn three_components() -> PlayerGraph {
+        let mut graph = graph_from_edges(&[("x", "y"), ("a", "b"), ("b", "c"), ("c", "a")]);
+        graph.add_node(PlayerId::new("loner"));
+        graph
+    }
+
+    #[test]
+    fn labels_largest_component_first() {
+        let components = connected_components(&three_components());
+        assert_eq!(components.sizes, [3, 2, 1]);
+        assert_eq!((components.count(), components.giant_size()), (3, 3));
+        assert_eq!(components.giant_fraction(), 0.5);
+        assert_eq!(components.labels[&PlayerId::new("a")], 0);
+        assert_eq!(components.labels[&PlayerId::new("y")], 1);
+        assert_eq!(components.labels[&PlayerId::new("loner")], 2);
+        assert_eq!(components.size_distribution().into_iter().collect::<Vec<_>>(), [(1, 1), (2, 1), (3, 1)]);
+    }
+
+    #[test]
+    fn extracts_the_giant_component() {
+        let giant = giant_component(&three_components());
+        let players: Vec<&str> = giant.node_weights().map(|id| id.as_str()).collect();
+        assert_eq!(players, ["a", "b", "c"]);
+        assert_eq!(giant.edge_count(), 3);
+        assert_eq!(connected_components(&PlayerGraph::new_undirected()).giant_fraction(), 0.0);
+    }
+}
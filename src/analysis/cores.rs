@@ -0,0 +1,93 @@
+// src/analysis/cores.rs
+// Module: analysis::cores
+// Purpose: k-core decomposition of the player graph: each player's core number and the size of every k-shell.
+
+use super::traversal::{adjacency, min_degree_order};
+use crate::data_loader::PlayerId;
+use crate::graph_builder::PlayerGraph;
+use petgraph::graph::NodeIndex;
+use std::collections::HashMap;
+
+/// Core numbers and shell sizes of the whole graph
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CoreDecomposition {
+    /// Largest k such that the player belongs to the k-core
+    pub core_number: HashMap<PlayerId, usize>,
+    /// shell_sizes[k] = players whose core number is exactly k
+    pub shell_sizes: Vec<usize>,
+    /// Highest core number in the graph (0 for an empty graph)
+    pub degeneracy: usize,
+}
+
+impl CoreDecomposition {
+    /// core_sizes()[k] = players in the k-core (core number >= k)
+    pub fn core_sizes(&self) -> Vec<usize> {
+        let mut sizes = self.shell_sizes.clone();
+        for k in (0..sizes.len().saturating_sub(1)).rev() {
+            sizes[k] += sizes[k + 1];
+        }
+        sizes
+    }
+
+    /// Players in the k-core, highest core number first (ties by name)
+    pub fn core_members(&self, k: usize) -> Vec<PlayerId> {
+        let mut members: Vec<(&PlayerId, usize)> = self
+            .core_number
+            .iter()
+            .filter(|&(_, &core)| core >= k)
+            .map(|(id, &core)| (id, core))
+            .collect();
+        members.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        members.into_iter().map(|(id, _)| id.clone()).collect()
+    }
+}
+
+/// Peels minimum-degree players with a bucket queue in O(n + m).
+/// A player's core number is the highest removal degree seen up to and
+/// including their own removal.
+pub fn core_decomposition(graph: &PlayerGraph) -> CoreDecomposition {
+    let adj = adjacency(graph);
+    let mut core_number = HashMap::with_capacity(adj.len());
+    let mut shell_sizes: Vec<usize> = Vec::new();
+    let mut level = 0;
+    for (v, degree) in min_degree_order(&adj) {
+        level = level.max(degree);
+        if shell_sizes.len() <= level {
+            shell_sizes.resize(level + 1, 0);
+        }
+        shell_sizes[level] += 1;
+        core_number.insert(graph[NodeIndex::new(v)].clone(), level);
+    }
+    CoreDecomposition { core_number, shell_sizes, degeneracy: level }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_builder::graph_from_edges;
+
+    #[test]
+    fn peels_a_clique_with_a_tail() {
+        // K4 on a..d, then a - e - f hanging off it
+        let graph = graph_from_edges(&[
+            ("a", "b"), ("a", "c"), ("a", "d"), ("b", "c"), ("b", "d"), ("c", "d"),
+            ("a", "e"), ("e", "f"),
+        ]);
+        let cores = core_decomposition(&graph);
+        assert_eq!(cores.degeneracy, 3);
+        assert_eq!(cores.shell_sizes, [0, 2, 0, 4]);
+        assert_eq!(cores.core_sizes(), [6, 6, 4, 4]);
+        assert_eq!(cores.core_number[&PlayerId::new("a")], 3);
+        assert_eq!(cores.core_number[&PlayerId::new("e")], 1);
+        let innermost = cores.core_members(3);
+        assert_eq!(innermost.iter().map(|id| id.as_str()).collect::<Vec<_>>(), ["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn cycle_is_a_single_two_core() {
+        let graph = graph_from_edges(&[("a", "b"), ("b", "c"), ("c", "d"), ("d", "e"), ("e", "a")]);
+        let cores = core_decomposition(&graph);
+        assert_eq!((cores.degeneracy, cores.shell_sizes.as_slice()), (2, [0, 0, 5].as_slice()));
+    }
+}
@@ -0,0 +1,294 @@
+// src/analysis/density.rs
+// Module: analysis::density
+// Purpose: Densest subgraph by Charikar's greedy peeling (2-approximation) or exactly via Goldberg's max-flow reduction.
+
+use super::traversal::{adjacency, min_degree_order};
+use crate::data_loader::PlayerId;
+use crate::graph_builder::PlayerGraph;
+use petgraph::graph::NodeIndex;
+use std::collections::VecDeque;
+
+/// How `densest_subgraph` searches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DensestMethod {
+    /// Charikar's greedy peeling: fast, at least half the optimal density
+    #[default]
+    Peeling,
+    /// Goldberg's parametric max-flow: optimal, seeded and pruned by peeling
+    Exact,
+}
+
+/// A group of players and how tightly they are linked
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DenseSubgraph {
+    /// Members, most teammates inside the group first (ties by name)
+    pub players: Vec<PlayerId>,
+    /// Teammate links among the members
+    pub edges: usize,
+    /// edges / players, i.e. half the average degree inside the group
+    pub density: f64,
+}
+
+/// Two flow values closer than this are treated as equal
+const FLOW_EPSILON: f64 = 1e-9;
+
+/// Finds the set of players maximizing internal links per player
+pub fn densest_subgraph(graph: &PlayerGraph, method: DensestMethod) -> DenseSubgraph {
+    let adj = adjacency(graph);
+    let mut members = peel_densest(&adj);
+    if method == DensestMethod::Exact && !members.is_empty() {
+        members = exact_densest(&adj, members);
+    }
+
+    let mut inside = vec![false; adj.len()];
+    members.iter().for_each(|&v| inside[v] = true);
+    let mut ranked: Vec<(usize, &PlayerId)> = members
+        .iter()
+        .map(|&v| (adj[v].iter().filter(|&&u| inside[u]).count(), &graph[NodeIndex::new(v)]))
+        .collect();
+    ranked.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(b.1)));
+
+    let edges = ranked.iter().map(|(degree, _)| degree).sum::<usize>() / 2;
+    let density = if members.is_empty() { 0.0 } else { edges as f64 / members.len() as f64 };
+    let players = ranked.into_iter().map(|(_, id)| id.clone()).collect();
+    DenseSubgraph { players, edges, density }
+}
+
+/// Charikar's peeling: the densest of the n nested subgraphs left while
+/// repeatedly removing a minimum-degree node
+fn peel_densest(adj: &[Vec<usize>]) -> Vec<usize> {
+    let order = min_degree_order(adj);
+    let mut edges: usize = adj.iter().map(|nbrs| nbrs.len()).sum::<usize>() / 2;
+    let mut best = (0.0, 0); // (density, number of nodes peeled before it)
+    for (step, &(_, degree)) in order.iter().enumerate() {
+        let density = edges as f64 / (order.len() - step) as f64;
+        if density > best.0 {
+            best = (density, step);
+        }
+        edges -= degree;
+    }
+    order[best.1..].iter().map(|&(v, _)| v).collect()
+}
+
+/// Goldberg's reduction: binary search on density g, where a min cut below
+/// 2m proves some subgraph is denser than g. Only the ceil(g0)-core can hold
+/// a subgraph denser than the peeling result g0, so the search runs there.
+fn exact_densest(adj: &[Vec<usize>], seed: Vec<usize>) -> Vec<usize> {
+    let n = seed.len();
+    let seed_edges = count_edges(adj, &seed);
+    let mut lo = seed_edges as f64 / n as f64;
+    let mut best = seed;
+
+    // Restrict to the ceil(lo)-core, relabelled 0..k
+    let threshold = lo.ceil() as usize;
+    let mut core_level = 0;
+    let mut local = vec![usize::MAX; adj.len()];
+    let mut core: Vec<usize> = Vec::new();
+    for (v, degree) in min_degree_order(adj) {
+        core_level = core_level.max(degree);
+        if core_level >= threshold {
+            local[v] = core.len();
+            core.push(v);
+        }
+    }
+    let sub: Vec<Vec<usize>> = core
+        .iter()
+        .map(|&v| adj[v].iter().filter(|&&u| local[u] != usize::MAX).map(|&u| local[u]).collect())
+        .collect();
+    let k = sub.len() as f64;
+
+    // Distinct densities with at most k nodes differ by at least 1 / k^2
+    let mut hi = 2.0 * lo;
+    while hi - lo >= 1.0 / (k * k) {
+        let g = (lo + hi) / 2.0;
+        let members: Vec<usize> = denser_than(&sub, g).iter().map(|&v| core[v]).collect();
+        let density = if members.is_empty() { 0.0 } else { count_edges(adj, &members) as f64 / members.len() as f64 };
+        // Rounding can surface a cut that is not actually denser than g
+        if density <= g {
+            hi = g;
+        } else {
+            lo = density;
+            best = members;
+        }
+    }
+    best
+}
+
+/// Number of edges with both ends in `members`
+fn count_edges(adj: &[Vec<usize>], members: &[usize]) -> usize {
+    let mut inside = vec![false; adj.len()];
+    members.iter().for_each(|&v| inside[v] = true);
+    members.iter().map(|&v| adj[v].iter().filter(|&&u| inside[u]).count()).sum::<usize>() / 2
+}
+
+/// Nodes of a subgraph with density above `g`, or empty if none exists.
+/// Network: source -> v with capacity deg(v), v -> sink with 2g, and
+/// capacity 1 both ways along each edge. A source side S has cut
+/// 2m + 2(g|S| - |E(S)|).
+fn denser_than(adj: &[Vec<usize>], g: f64) -> Vec<usize> {
+    let n = adj.len();
+    let (source, sink) = (n, n + 1);
+    let mut network = FlowNetwork::new(n + 2);
+    for (v, nbrs) in adj.iter().enumerate() {
+        network.add_arc(source, v, nbrs.len() as f64, 0.0);
+        network.add_arc(v, sink, 2.0 * g, 0.0);
+        for &u in nbrs {
+            if v < u {
+                network.add_arc(v, u, 1.0, 1.0);
+            }
+        }
+    }
+    network.max_flow(source, sink);
+    network.reachable(source).into_iter().filter(|&v| v < n).collect()
+}
+
+/// One direction of a residual edge
+#[derive(Clone, Copy)]
+struct ResidualArc {
+    to: usize,
+    capacity: f64,
+    reverse: usize, // index of the paired arc in `arcs[to]`
+}
+
+/// Dinic's max-flow on real capacities
+struct FlowNetwork {
+    arcs: Vec<Vec<ResidualArc>>,
+    level: Vec<usize>,
+    next_arc: Vec<usize>,
+}
+
+impl FlowNetwork {
+    fn new(nodes: usize) -> Self {
+        FlowNetwork { arcs: (0..nodes).map(|_| Vec::new()).collect(), level: vec![0; nodes], next_arc: vec![0; nodes] }
+    }
+
+    /// Adds a -> b with `forward` capacity and b -> a with `backward`
+    fn add_arc(&mut self, a: usize, b: usize, forward: f64, backward: f64) {
+        let (ra, rb) = (self.arcs[b].len(), self.arcs[a].len());
+        self.arcs[a].push(ResidualArc { to: b, capacity: forward, reverse: ra });
+        self.arcs[b].push(ResidualArc { to: a, capacity: backward, reverse: rb });
+    }
+
+    fn max_flow(&mut self, source: usize, sink: usize) -> f64 {
+        let mut flow = 0.0;
+        while self.build_levels(source, sink) {
+            self.next_arc.iter_mut().for_each(|a| *a = 0);
+            loop {
+                let pushed = self.augment(source, sink, f64::INFINITY);
+                if pushed <= FLOW_EPSILON {
+                    break;
+                }
+                flow += pushed;
+            }
+        }
+        flow
+    }
+
+    /// BFS levels over arcs with spare capacity; false once the sink is cut off
+    fn build_levels(&mut self, source: usize, sink: usize) -> bool {
+        self.level.iter_mut().for_each(|l| *l = usize::MAX);
+        self.level[source] = 0;
+        let mut queue = VecDeque::from([source]);
+        while let Some(v) = queue.pop_front() {
+            for arc in &self.arcs[v] {
+                if arc.capacity > FLOW_EPSILON && self.level[arc.to] == usize::MAX {
+                    self.level[arc.to] = self.level[v] + 1;
+                    queue.push_back(arc.to);
+                }
+            }
+        }
+        self.level[sink] != usize::MAX
+    }
+
+    /// Pushes up to `limit` along one level-increasing path
+    fn augment(&mut self, v: usize, sink: usize, limit: f64) -> f64 {
+        if v == sink {
+            return limit;
+        }
+        while self.next_arc[v] < self.arcs[v].len() {
+            let ResidualArc { to, capacity, reverse } = self.arcs[v][self.next_arc[v]];
+            if capacity > FLOW_EPSILON && self.level[to] == self.level[v] + 1 {
+                let pushed = self.augment(to, sink, limit.min(capacity));
+                if pushed > FLOW_EPSILON {
+                    let i = self.next_arc[v];
+                    self.arcs[v][i].capacity -= pushed;
+                    self.arcs[to][reverse].capacity += pushed;
+                    return pushed;
+                }
+            }
+            self.next_arc[v] += 1;
+        }
+        0.0
+    }
+
+    /// Nodes reachable from `source` through arcs with spare capacity
+    fn reachable(&self, source: usize) -> Vec<usize> {
+        let mut seen = vec![false; self.arcs.len()];
+        seen[source] = true;
+        let mut stack = vec![source];
+        let mut found = Vec::new();
+        while let Some(v) = stack.pop() {
+            found.push(v);
+            for arc in &self.arcs[v] {
+                if arc.capacity > FLOW_EPSILON && !seen[arc.to] {
+                    seen[arc.to] = true;
+                    stack.push(arc.to);
+                }
+            }
+        }
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_builder::graph_from_edges;
+    use rand::prelude::*;
+
+    /// Best density over every non-empty node subset
+    fn brute_force_density(adj: &[Vec<usize>]) -> f64 {
+        (1u32..1 << adj.len())
+            .map(|mask| {
+                let members: Vec<usize> = (0..adj.len()).filter(|&v| mask & (1 << v) != 0).collect();
+                count_edges(adj, &members) as f64 / members.len() as f64
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn finds_the_clique_inside_a_sparse_fringe() {
+        let graph = graph_from_edges(&[
+            ("a", "b"), ("a", "c"), ("a", "d"), ("b", "c"), ("b", "d"), ("c", "d"),
+            ("a", "e"), ("e", "f"), ("f", "g"),
+        ]);
+        for method in [DensestMethod::Peeling, DensestMethod::Exact] {
+            let dense = densest_subgraph(&graph, method);
+            let mut players: Vec<&str> = dense.players.iter().map(|id| id.as_str()).collect();
+            players.sort();
+            assert_eq!(players, ["a", "b", "c", "d"]);
+            assert_eq!((dense.edges, dense.density), (6, 1.5));
+        }
+    }
+
+    #[test]
+    fn exact_matches_brute_force_on_random_graphs() {
+        let names: Vec<String> = (0..11).map(|i| format!("p{}", i)).collect();
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..20 {
+            let edges: Vec<(&str, &str)> = (0..names.len())
+                .flat_map(|i| (i + 1..names.len()).map(move |j| (i, j)))
+                .filter(|_| rng.random::<f64>() < 0.35)
+                .map(|(i, j)| (names[i].as_str(), names[j].as_str()))
+                .collect();
+            let graph = graph_from_edges(&edges);
+            let optimum = brute_force_density(&adjacency(&graph));
+
+            let exact = densest_subgraph(&graph, DensestMethod::Exact);
+            assert!((exact.density - optimum).abs() < 1e-9, "exact {} vs optimum {}", exact.density, optimum);
+            let peeled = densest_subgraph(&graph, DensestMethod::Peeling);
+            assert!(peeled.density >= optimum / 2.0 - 1e-9);
+        }
+    }
+}
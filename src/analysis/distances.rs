@@ -0,0 +1,119 @@
+// src/analysis/distances.rs
+// Module: analysis::distances
+// Purpose: Exact eccentricity, diameter, radius, and average shortest-path length per connected component via parallel all-sources BFS.
+
+use super::traversal::{adjacency, bfs, component_labels, parallel_map};
+use crate::data_loader::PlayerId;
+use crate::graph_builder::PlayerGraph;
+use std::collections::HashMap;
+
+/// Distance statistics for one connected component
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ComponentDistances {
+    pub size: usize,
+    pub diameter: usize,
+    pub radius: usize,
+    pub average_path_length: f64, // over ordered pairs of distinct members; 0 for singletons
+}
+
+/// Exact hop-distance statistics for the whole graph
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DistanceStats {
+    /// Largest component first
+    pub components: Vec<ComponentDistances>,
+    /// Longest shortest path in any component
+    pub diameter: usize,
+    /// Radius of the largest component
+    pub radius: usize,
+    /// Mean shortest-path length over all connected pairs
+    pub average_path_length: f64,
+    /// Greatest distance from each player to anyone in their component
+    pub eccentricity: HashMap<PlayerId, usize>,
+}
+
+/// Runs BFS from every player (in parallel) and aggregates exact
+/// eccentricity, diameter, radius and average shortest path per component.
+/// Edge weights are ignored: distance is the number of teammate hops.
+pub fn compute_distance_stats(graph: &PlayerGraph) -> DistanceStats {
+    let adj = adjacency(graph);
+    let labels = component_labels(&adj);
+
+    // (eccentricity, sum of distances) for every source; BFS visits
+    // nodes in distance order, so the last one reached is the farthest
+    let per_source: Vec<(u32, u64)> = parallel_map(adj.len(), |source| {
+        let mut dist = Vec::new();
+        let reached = bfs(&adj, source, &mut dist);
+        let ecc = reached.last().map(|&v| dist[v]).unwrap_or(0);
+        let total: u64 = reached.iter().map(|&v| dist[v] as u64).sum();
+        (ecc, total)
+    });
+
+    let component_count = labels.iter().max().map(|&m| m + 1).unwrap_or(0);
+    let mut components = vec![
+        ComponentDistances { size: 0, diameter: 0, radius: usize::MAX, average_path_length: 0.0 };
+        component_count
+    ];
+    let mut path_totals = vec![0u64; component_count];
+
+    for (node, &(ecc, total)) in per_source.iter().enumerate() {
+        let c = &mut components[labels[node]];
+        c.size += 1;
+        c.diameter = c.diameter.max(ecc as usize);
+        c.radius = c.radius.min(ecc as usize);
+        path_totals[labels[node]] += total;
+    }
+
+    let mut all_pairs = 0u64;
+    let mut all_total = 0u64;
+    for (c, &total) in components.iter_mut().zip(&path_totals) {
+        let pairs = (c.size * (c.size - 1)) as u64;
+        c.average_path_length = if pairs > 0 { total as f64 / pairs as f64 } else { 0.0 };
+        all_pairs += pairs;
+        all_total += total;
+    }
+
+    let eccentricity = graph
+        .node_indices()
+        .map(|n| (graph[n].clone(), per_source[n.index()].0 as usize))
+        .collect();
+
+    DistanceStats {
+        diameter: components.iter().map(|c| c.diameter).max().unwrap_or(0),
+        radius: components.first().map(|c| c.radius).unwrap_or(0),
+        average_path_length: if all_pairs > 0 { all_total as f64 / all_pairs as f64 } else { 0.0 },
+        components,
+        eccentricity,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_builder::graph_from_edges;
+
+    #[test]
+    fn measures_each_component_separately() {
+        // Path a - b - c - d and the separate pair x - y
+        let stats = compute_distance_stats(&graph_from_edges(&[("a", "b"), ("b", "c"), ("c", "d"), ("x", "y")]));
+        let sizes: Vec<usize> = stats.components.iter().map(|c| c.size).collect();
+        assert_eq!(sizes, [4, 2]);
+        assert_eq!((stats.diameter, stats.radius), (3, 2));
+        // Path distances 1+2+3+1+2+1 = 10 per direction over 12 ordered pairs
+        assert!((stats.components[0].average_path_length - 20.0 / 12.0).abs() < 1e-12);
+        assert_eq!(stats.components[1].average_path_length, 1.0);
+        assert!((stats.average_path_length - 22.0 / 14.0).abs() < 1e-12);
+        assert_eq!(stats.eccentricity[&PlayerId::new("a")], 3);
+        assert_eq!(stats.eccentricity[&PlayerId::new("b")], 2);
+        assert_eq!(stats.eccentricity[&PlayerId::new("x")], 1);
+    }
+
+    #[test]
+    fn singletons_have_zero_distances() {
+        let mut graph = PlayerGraph::new_undirected();
+        graph.add_node(PlayerId::new("loner"));
+        let stats = compute_distance_stats(&graph);
+        assert_eq!((stats.diameter, stats.radius, stats.average_path_length), (0, 0, 0.0));
+    }
+}
@@ -0,0 +1,265 @@
+// src/analysis/paths.rs
+// Module: analysis::paths
+// Purpose: "Six degrees" queries: teammate chains between two players, with the team-seasons linking each hop.
+
+use super::traversal::{adjacency, UNREACHED};
+use crate::data_loader::PlayerId;
+use crate::graph_builder::{find_player, PlayerGraph, SharedStint};
+use petgraph::graph::NodeIndex;
+use std::collections::{HashSet, VecDeque};
+
+/// Which chains `find_teammate_paths` returns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathQuery {
+    /// One shortest chain
+    Shortest,
+    /// Every shortest chain, up to `limit` of them
+    AllShortest { limit: usize },
+    /// The `k` shortest loopless chains, possibly of different lengths (Yen's algorithm)
+    Alternatives { k: usize },
+}
+
+/// One link of a chain: two teammates and the team-seasons they shared
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PathHop {
+    pub from: PlayerId,
+    pub to: PlayerId,
+    pub stints: Vec<SharedStint>,
+}
+
+/// A chain of teammates from one player to another
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TeammatePath {
+    /// Both endpoints included
+    pub players: Vec<PlayerId>,
+    pub hops: Vec<PathHop>,
+}
+
+impl TeammatePath {
+    /// Number of teammate links in the chain
+    pub fn len(&self) -> usize {
+        self.hops.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hops.is_empty()
+    }
+}
+
+/// The shortest teammate chain from `from` to `to`; None if either player
+/// is missing or they are in different components
+pub fn shortest_teammate_path(graph: &PlayerGraph, from: &PlayerId, to: &PlayerId) -> Option<TeammatePath> {
+    find_teammate_paths(graph, from, to, PathQuery::Shortest).into_iter().next()
+}
+
+/// Teammate chains from `from` to `to`, shortest first. Hops are counted
+/// unweighted: any shared team-season is one link. Empty if either player
+/// is missing or no chain exists.
+pub fn find_teammate_paths(graph: &PlayerGraph, from: &PlayerId, to: &PlayerId, query: PathQuery) -> Vec<TeammatePath> {
+    let (Some(source), Some(target)) = (find_player(graph, from), find_player(graph, to)) else {
+        return Vec::new();
+    };
+    let adj = adjacency(graph);
+    let (source, target) = (source.index(), target.index());
+
+    let node_paths = match query {
+        PathQuery::Shortest => shortest_path(&adj, source, target, &Blocked::default()).into_iter().collect(),
+        PathQuery::AllShortest { limit } => all_shortest_paths(&adj, source, target, limit),
+        PathQuery::Alternatives { k } => yen_paths(&adj, source, target, k),
+    };
+    node_paths.iter().map(|path| describe(graph, path)).collect()
+}
+
+/// Nodes and edges a search must avoid
+#[derive(Default)]
+struct Blocked {
+    nodes: HashSet<usize>,
+    edges: HashSet<(usize, usize)>, // (lower, higher)
+}
+
+impl Blocked {
+    fn allows(&self, u: usize, v: usize) -> bool {
+        !self.nodes.contains(&v) && !self.edges.contains(&(u.min(v), u.max(v)))
+    }
+}
+
+/// BFS from `source` to `target` around `blocked`, as a node sequence
+fn shortest_path(adj: &[Vec<usize>], source: usize, target: usize, blocked: &Blocked) -> Option<Vec<usize>> {
+    let mut parent = vec![usize::MAX; adj.len()];
+    parent[source] = source;
+    let mut queue = VecDeque::from([source]);
+    while let Some(u) = queue.pop_front() {
+        if u == target {
+            let mut path = vec![target];
+            while *path.last().unwrap() != source {
+                path.push(parent[*path.last().unwrap()]);
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for &v in &adj[u] {
+            if parent[v] == usize::MAX && blocked.allows(u, v) {
+                parent[v] = u;
+                queue.push_back(v);
+            }
+        }
+    }
+    None
+}
+
+/// Every shortest path, found by walking back from `target` through nodes
+/// one hop closer to `source`
+fn all_shortest_paths(adj: &[Vec<usize>], source: usize, target: usize, limit: usize) -> Vec<Vec<usize>> {
+    let mut dist = vec![UNREACHED; adj.len()];
+    dist[source] = 0;
+    let mut queue = VecDeque::from([source]);
+    while let Some(u) = queue.pop_front() {
+        if u == target {
+            break;
+        }
+        for &v in &adj[u] {
+            if dist[v] == UNREACHED {
+                dist[v] = dist[u] + 1;
+                queue.push_back(v);
+            }
+        }
+    }
+    if dist[target] == UNREACHED {
+        return Vec::new();
+    }
+
+    // Depth-first over predecessors; `stack` holds partial paths ending at target
+    let mut paths = Vec::new();
+    let mut stack = vec![vec![target]];
+    while let Some(partial) = stack.pop() {
+        if paths.len() >= limit {
+            break;
+        }
+        let head = *partial.last().unwrap();
+        if head == source {
+            paths.push(partial.into_iter().rev().collect());
+            continue;
+        }
+        for &v in adj[head].iter().rev() {
+            if dist[v] != UNREACHED && dist[v] + 1 == dist[head] {
+                let mut next = partial.clone();
+                next.push(v);
+                stack.push(next);
+            }
+        }
+    }
+    paths
+}
+
+/// Yen's k shortest loopless paths over hop counts
+fn yen_paths(adj: &[Vec<usize>], source: usize, target: usize, k: usize) -> Vec<Vec<usize>> {
+    if k == 0 {
+        return Vec::new();
+    }
+    let Some(first) = shortest_path(adj, source, target, &Blocked::default()) else {
+        return Vec::new();
+    };
+    let mut accepted = vec![first];
+    let mut candidates: Vec<Vec<usize>> = Vec::new();
+
+    while accepted.len() < k {
+        let previous = accepted.last().unwrap().clone();
+        for spur_index in 0..previous.len() - 1 {
+            let root = &previous[..=spur_index];
+            let mut blocked = Blocked::default();
+            // Leave the root only by an edge no accepted path with this root already used
+            for path in &accepted {
+                if path.len() > spur_index + 1 && &path[..=spur_index] == root {
+                    let (a, b) = (path[spur_index], path[spur_index + 1]);
+                    blocked.edges.insert((a.min(b), a.max(b)));
+                }
+            }
+            blocked.nodes.extend(&root[..spur_index]);
+
+            if let Some(spur) = shortest_path(adj, root[spur_index], target, &blocked) {
+                let mut path = root[..spur_index].to_vec();
+                path.extend(spur);
+                if !candidates.contains(&path) && !accepted.contains(&path) {
+                    candidates.push(path);
+                }
+            }
+        }
+        if candidates.is_empty() {
+            break;
+        }
+        // Shortest candidate next; ties by node order for a stable result
+        let best = (0..candidates.len())
+            .min_by(|&a, &b| candidates[a].len().cmp(&candidates[b].len()).then(candidates[a].cmp(&candidates[b])))
+            .unwrap();
+        accepted.push(candidates.swap_remove(best));
+    }
+    accepted
+}
+
+/// Attaches player ids and shared stints to a node sequence
+fn describe(graph: &PlayerGraph, path: &[usize]) -> TeammatePath {
+    let players: Vec<PlayerId> = path.iter().map(|&v| graph[NodeIndex::new(v)].clone()).collect();
+    let hops = path
+        .windows(2)
+        .map(|pair| {
+            let (a, b) = (NodeIndex::new(pair[0]), NodeIndex::new(pair[1]));
+            let stints = graph.find_edge(a, b).map(|e| graph[e].stints.clone()).unwrap_or_default();
+            PathHop { from: graph[a].clone(), to: graph[b].clone(), stints }
+        })
+        .collect();
+    TeammatePath { players, hops }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_builder::graph_from_edges;
+
+    /// Square a - b - c - d - a with a tail c - e
+    fn square() -> PlayerGraph {
+        graph_from_edges(&[("a", "b"), ("b", "c"), ("c", "d"), ("d", "a"), ("c", "e")])
+    }
+
+    fn names(paths: &[TeammatePath]) -> Vec<Vec<String>> {
+        paths.iter().map(|p| p.players.iter().map(|id| id.to_string()).collect()).collect()
+    }
+
+    fn query(from: &str, to: &str, query: PathQuery) -> Vec<TeammatePath> {
+        find_teammate_paths(&square(), &PlayerId::new(from), &PlayerId::new(to), query)
+    }
+
+    #[test]
+    fn shortest_path_lists_each_hop() {
+        let path = shortest_teammate_path(&square(), &PlayerId::new("a"), &PlayerId::new("e")).unwrap();
+        assert_eq!(path.len(), 3);
+        assert_eq!(path.players.first().map(PlayerId::as_str), Some("a"));
+        assert_eq!(path.players.last().map(PlayerId::as_str), Some("e"));
+        assert!(path.hops.iter().zip(path.players.windows(2)).all(|(hop, pair)| hop.from == pair[0] && hop.to == pair[1]));
+    }
+
+    #[test]
+    fn lists_every_tied_shortest_path() {
+        let mut all = names(&query("a", "e", PathQuery::AllShortest { limit: 10 }));
+        all.sort();
+        assert_eq!(all, [["a", "b", "c", "e"], ["a", "d", "c", "e"]]);
+        assert_eq!(query("a", "e", PathQuery::AllShortest { limit: 1 }).len(), 1);
+        assert!(query("a", "e", PathQuery::AllShortest { limit: 0 }).is_empty());
+    }
+
+    #[test]
+    fn yen_finds_longer_loopless_alternatives() {
+        let paths = names(&query("a", "b", PathQuery::Alternatives { k: 5 }));
+        assert_eq!(paths, [vec!["a", "b"], vec!["a", "d", "c", "b"]]);
+        assert!(query("a", "b", PathQuery::Alternatives { k: 0 }).is_empty());
+    }
+
+    #[test]
+    fn missing_or_disconnected_players_have_no_paths() {
+        assert!(query("a", "nobody", PathQuery::Shortest).is_empty());
+        let mut graph = square();
+        graph.add_node(PlayerId::new("loner"));
+        assert!(shortest_teammate_path(&graph, &PlayerId::new("a"), &PlayerId::new("loner")).is_none());
+    }
+}
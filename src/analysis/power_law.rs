@@ -0,0 +1,250 @@
+// src/analysis/power_law.rs
+// Module: analysis::power_law
+// Purpose: Clauset–Shalizi–Newman discrete power-law fit of the degree distribution: MLE exponent, KS-selected x_min, bootstrap goodness of fit.
+
+use super::traversal::parallel_map;
+use rand::prelude::*;
+use std::collections::HashMap;
+
+/// Settings for `fit_power_law`
+#[derive(Debug, Clone)]
+pub struct PowerLawOptions {
+    /// Synthetic data sets for the goodness-of-fit p-value; 0 skips it
+    pub bootstraps: usize,
+    pub seed: u64,
+    /// Smallest tail (degrees >= x_min) a candidate x_min may leave
+    pub min_tail: usize,
+}
+
+impl Default for PowerLawOptions {
+    fn default() -> Self {
+        PowerLawOptions { bootstraps: 100, seed: 42, min_tail: 10 }
+    }
+}
+
+/// A fitted discrete power law P(k) ∝ k^-alpha for k >= x_min
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PowerLawFit {
+    pub alpha: f64,
+    pub x_min: usize,
+    /// Asymptotic standard error of alpha, (alpha - 1) / sqrt(tail_size)
+    pub std_error: f64,
+    /// Largest gap between the empirical and fitted tail CDFs
+    pub ks_statistic: f64,
+    /// Players with degree >= x_min
+    pub tail_size: usize,
+    /// Players with degree >= 1
+    pub sample_size: usize,
+    /// Share of synthetic power-law data sets fitting worse than the real
+    /// one; below 0.1 the power law is ruled out (Clauset et al. 2009)
+    pub p_value: Option<f64>,
+}
+
+impl PowerLawFit {
+    /// Number of players the fit predicts at degree `k` (k >= x_min)
+    pub fn expected_count(&self, k: usize) -> f64 {
+        if k < self.x_min {
+            return 0.0;
+        }
+        self.tail_size as f64 * (k as f64).powf(-self.alpha) / hurwitz_zeta(self.alpha, self.x_min as f64)
+    }
+}
+
+/// Bounds of the exponent search; steep cut-off tails can push alpha well past 3
+const ALPHA_MIN: f64 = 1.01;
+const ALPHA_MAX: f64 = 64.0;
+
+/// Fits a discrete power law to a degree histogram (degree -> players).
+/// For every candidate x_min the exponent is the exact discrete MLE, and
+/// the x_min whose fit has the smallest KS distance wins. None when fewer
+/// than `min_tail` players have degree >= 1.
+pub fn fit_power_law(degree_counts: &HashMap<usize, usize>, options: &PowerLawOptions) -> Option<PowerLawFit> {
+    let mut degrees: Vec<usize> = degree_counts
+        .iter()
+        .filter(|&(&d, _)| d > 0)
+        .flat_map(|(&d, &count)| std::iter::repeat_n(d, count))
+        .collect();
+    degrees.sort_unstable();
+
+    let mut fit = best_fit(&degrees, options.min_tail)?;
+    if options.bootstraps > 0 {
+        let worse = parallel_map(options.bootstraps, |b| {
+            let mut rng = StdRng::seed_from_u64(options.seed.wrapping_add(b as u64));
+            let synthetic = synthetic_sample(&degrees, &fit, &mut rng);
+            best_fit(&synthetic, options.min_tail).is_none_or(|f| f.ks_statistic >= fit.ks_statistic)
+        });
+        fit.p_value = Some(worse.iter().filter(|&&w| w).count() as f64 / options.bootstraps as f64);
+    }
+    Some(fit)
+}
+
+/// Scans every distinct value as x_min and keeps the fit with the smallest KS distance
+fn best_fit(sorted: &[usize], min_tail: usize) -> Option<PowerLawFit> {
+    let n = sorted.len();
+    // suffix_logs[i] = sum of ln(x) over sorted[i..]
+    let mut suffix_logs = vec![0.0; n + 1];
+    for i in (0..n).rev() {
+        suffix_logs[i] = suffix_logs[i + 1] + (sorted[i] as f64).ln();
+    }
+
+    let mut best: Option<PowerLawFit> = None;
+    let mut start = 0;
+    while start < n && n - start >= min_tail.max(2) {
+        let x_min = sorted[start];
+        let tail = &sorted[start..];
+        let alpha = maximize_likelihood(tail.len() as f64, suffix_logs[start], x_min as f64);
+        let ks_statistic = ks_distance(tail, alpha);
+        if best.as_ref().is_none_or(|b| ks_statistic < b.ks_statistic) {
+            best = Some(PowerLawFit {
+                alpha,
+                x_min,
+                std_error: (alpha - 1.0) / (tail.len() as f64).sqrt(),
+                ks_statistic,
+                tail_size: tail.len(),
+                sample_size: n,
+                p_value: None,
+            });
+        }
+        // Next distinct value
+        start += tail.partition_point(|&x| x == x_min);
+    }
+    best
+}
+
+/// Exponent maximizing -n ln ζ(α, x_min) - α Σ ln x, by golden-section
+/// search (the log-likelihood is concave in α)
+fn maximize_likelihood(n: f64, sum_logs: f64, x_min: f64) -> f64 {
+    let log_likelihood = |alpha: f64| -n * hurwitz_zeta(alpha, x_min).ln() - alpha * sum_logs;
+    // Widen the bracket until the likelihood turns down, so the maximum lies below `hi`
+    let mut hi = 4.0;
+    while hi < ALPHA_MAX && log_likelihood(hi) > log_likelihood(hi / 2.0) {
+        hi *= 2.0;
+    }
+
+    let ratio = (5f64.sqrt() - 1.0) / 2.0;
+    let mut lo = ALPHA_MIN;
+    let mut a = hi - ratio * (hi - lo);
+    let mut b = lo + ratio * (hi - lo);
+    let (mut fa, mut fb) = (log_likelihood(a), log_likelihood(b));
+    while hi - lo > 1e-7 {
+        if fa < fb {
+            lo = a;
+            a = b;
+            fa = fb;
+            b = lo + ratio * (hi - lo);
+            fb = log_likelihood(b);
+        } else {
+            hi = b;
+            b = a;
+            fb = fa;
+            a = hi - ratio * (hi - lo);
+            fa = log_likelihood(a);
+        }
+    }
+    (lo + hi) / 2.0
+}
+
+/// max |S(x) - P(x)| over the tail's distinct values, where S is the
+/// empirical CDF and P(x) = 1 - ζ(α, x + 1) / ζ(α, x_min)
+fn ks_distance(tail: &[usize], alpha: f64) -> f64 {
+    let n = tail.len() as f64;
+    let norm = hurwitz_zeta(alpha, tail[0] as f64);
+    let mut distance: f64 = 0.0;
+    let mut i = 0;
+    while i < tail.len() {
+        let x = tail[i];
+        i += tail[i..].partition_point(|&v| v == x);
+        let empirical = i as f64 / n;
+        let model = 1.0 - hurwitz_zeta(alpha, x as f64 + 1.0) / norm;
+        distance = distance.max((empirical - model).abs());
+    }
+    distance
+}
+
+/// Semi-parametric bootstrap sample: values below x_min are resampled from
+/// the data, the tail is drawn from the fitted power law
+fn synthetic_sample(sorted: &[usize], fit: &PowerLawFit, rng: &mut StdRng) -> Vec<usize> {
+    let body = &sorted[..sorted.len() - fit.tail_size];
+    let tail_share = fit.tail_size as f64 / sorted.len() as f64;
+    let mut sample: Vec<usize> = (0..sorted.len())
+        .map(|_| {
+            if body.is_empty() || rng.random::<f64>() < tail_share {
+                // Discrete power-law deviate via the rounded continuous inverse CDF (Clauset et al., App. D)
+                let r: f64 = rng.random();
+                ((fit.x_min as f64 - 0.5) * (1.0 - r).powf(-1.0 / (fit.alpha - 1.0)) + 0.5).floor() as usize
+            } else {
+                body[rng.random_range(0..body.len())]
+            }
+        })
+        .collect();
+    sample.sort_unstable();
+    sample
+}
+
+/// Hurwitz zeta ζ(s, q) = Σ_{k>=0} (q + k)^-s for s > 1, q > 0:
+/// a direct partial sum plus an Euler–Maclaurin tail correction
+fn hurwitz_zeta(s: f64, q: f64) -> f64 {
+    const TERMS: usize = 12;
+    // B_2j / (2j)! for j = 1..=5
+    const BERNOULLI: [f64; 5] = [1.0 / 12.0, -1.0 / 720.0, 1.0 / 30240.0, -1.0 / 1209600.0, 1.0 / 47900160.0];
+
+    let mut sum: f64 = (0..TERMS).map(|k| (q + k as f64).powf(-s)).sum();
+    let a = q + TERMS as f64;
+    sum += a.powf(1.0 - s) / (s - 1.0) + 0.5 * a.powf(-s);
+
+    // Rising factorial s (s+1) ... (s+2j-2) times a^(-s-2j+1)
+    let mut rising = s;
+    let mut power = a.powf(-s - 1.0);
+    for (j, b) in BERNOULLI.iter().enumerate() {
+        if j > 0 {
+            let m = 2.0 * j as f64;
+            rising *= (s + m - 1.0) * (s + m);
+            power /= a * a;
+        }
+        sum += b * rising * power;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hurwitz_zeta_matches_known_values() {
+        let pi2_6 = std::f64::consts::PI.powi(2) / 6.0;
+        assert!((hurwitz_zeta(2.0, 1.0) - pi2_6).abs() < 1e-12);
+        // ζ(s, q) = ζ(s, q + 1) + q^-s
+        assert!((hurwitz_zeta(2.5, 3.0) - hurwitz_zeta(2.5, 4.0) - 3f64.powf(-2.5)).abs() < 1e-12);
+        let direct: f64 = (0..200_000).map(|k| (7.0 + k as f64).powf(-3.5)).sum();
+        assert!((hurwitz_zeta(3.5, 7.0) - direct).abs() < 1e-12);
+    }
+
+    #[test]
+    fn recovers_the_exponent_of_a_synthetic_power_law() {
+        let truth = PowerLawFit { alpha: 2.5, x_min: 1, std_error: 0.0, ks_statistic: 0.0, tail_size: 5000, sample_size: 5000, p_value: None };
+        let mut rng = StdRng::seed_from_u64(1);
+        let sample = synthetic_sample(&vec![1; 5000], &truth, &mut rng);
+        let mut counts: HashMap<usize, usize> = HashMap::new();
+        sample.iter().for_each(|&k| *counts.entry(k).or_insert(0) += 1);
+
+        let fit = fit_power_law(&counts, &PowerLawOptions { bootstraps: 0, ..PowerLawOptions::default() }).unwrap();
+        assert!((fit.alpha - 2.5).abs() < 3.0 * fit.std_error + 0.05, "alpha {}", fit.alpha);
+        assert!(fit.ks_statistic < 0.05);
+        assert_eq!((fit.sample_size, fit.p_value), (5000, None));
+        // Expected counts over the tail add back up to its size
+        let expected: f64 = (fit.x_min..100_000).map(|k| fit.expected_count(k)).sum();
+        assert!((expected - fit.tail_size as f64).abs() / (fit.tail_size as f64) < 0.01);
+        assert_eq!(PowerLawFit { x_min: 5, ..truth }.expected_count(4), 0.0);
+    }
+
+    #[test]
+    fn rejects_a_narrow_bell_shaped_distribution() {
+        // Degrees piled around 50: no power-law tail
+        let counts: HashMap<usize, usize> = (30..=70).map(|k| (k, 400 - 15 * (k as i64 - 50).unsigned_abs() as usize)).collect();
+        let fit = fit_power_law(&counts, &PowerLawOptions { bootstraps: 20, ..PowerLawOptions::default() }).unwrap();
+        assert!(fit.p_value.unwrap() < 0.1);
+        assert!(fit_power_law(&HashMap::from([(3, 5)]), &PowerLawOptions::default()).is_none());
+    }
+}
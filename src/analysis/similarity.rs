@@ -0,0 +1,344 @@
+// src/analysis/similarity.rs
+// Module: analysis::similarity
+// Purpose: Top-k structural similarity between players (Jaccard, cosine, Adamic–Adar, overlap) from sparse neighbor intersections, optionally narrowed by MinHash/LSH.
+
+use super::traversal::{adjacency, parallel_map};
+use crate::data_loader::PlayerId;
+use crate::graph_builder::{find_player, PlayerGraph};
+use petgraph::graph::NodeIndex;
+use rand::prelude::*;
+use std::collections::HashMap;
+
+/// How two players' teammate sets are compared
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SimilarityMetric {
+    /// |A ∩ B| / |A ∪ B|
+    #[default]
+    Jaccard,
+    /// |A ∩ B| / sqrt(|A| |B|)
+    Cosine,
+    /// Sum of 1 / ln(degree) over shared teammates: rare shared teammates count more
+    AdamicAdar,
+    /// |A ∩ B| / min(|A|, |B|)
+    Overlap,
+}
+
+/// MinHash signatures split into LSH bands; only players colliding in at
+/// least one band are scored. Pairs with Jaccard s collide with probability
+/// 1 - (1 - s^rows)^bands.
+#[derive(Debug, Clone)]
+pub struct MinHashOptions {
+    pub bands: usize,
+    pub rows: usize,
+    pub seed: u64,
+}
+
+impl Default for MinHashOptions {
+    fn default() -> Self {
+        MinHashOptions { bands: 32, rows: 4, seed: 42 }
+    }
+}
+
+/// Settings for `most_similar_pairs` and `similar_players`
+#[derive(Debug, Clone)]
+pub struct SimilarityOptions {
+    pub metric: SimilarityMetric,
+    /// Number of pairs to return
+    pub top_k: usize,
+    /// Ignore candidates with fewer teammates than this; tiny teammate sets
+    /// produce trivial scores near 1.0. The player queried by
+    /// `similar_players` is scored regardless, with or without MinHash.
+    pub min_degree: usize,
+    /// Score only LSH candidate pairs instead of every pair sharing a teammate
+    pub minhash: Option<MinHashOptions>,
+}
+
+impl Default for SimilarityOptions {
+    fn default() -> Self {
+        SimilarityOptions { metric: SimilarityMetric::Jaccard, top_k: 10, min_degree: 10, minhash: None }
+    }
+}
+
+/// Two players and how alike their teammate sets are
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SimilarPair {
+    pub a: PlayerId,
+    pub b: PlayerId,
+    pub score: f64,
+    /// Teammates the two have in common
+    pub shared: usize,
+}
+
+/// The `top_k` most similar player pairs in the whole graph, best first
+pub fn most_similar_pairs(graph: &PlayerGraph, options: &SimilarityOptions) -> Vec<SimilarPair> {
+    let index = SimilarityIndex::new(graph, options);
+    let players: Vec<usize> = (0..index.adj.len()).filter(|&u| index.eligible(u)).collect();
+
+    // One scratch accumulator per worker rather than one per player
+    let workers = std::thread::available_parallelism().map(|t| t.get()).unwrap_or(1);
+    let chunks: Vec<&[usize]> = players.chunks(players.len().div_ceil(workers).max(1)).collect();
+    let per_chunk = parallel_map(chunks.len(), |c| {
+        let mut scratch = Scratch::new(index.adj.len());
+        let mut found = Vec::new();
+        for &u in chunks[c] {
+            let mut scored = index.scores_for(u, |v| v > u, &mut scratch);
+            top_k(&mut scored, options.top_k);
+            found.extend(scored.into_iter().map(|(v, score, shared)| (u, v, score, shared)));
+        }
+        found
+    });
+
+    let mut pairs: Vec<(usize, usize, f64, usize)> = per_chunk.into_iter().flatten().collect();
+    pairs.sort_by(|x, y| y.2.total_cmp(&x.2).then((x.0, x.1).cmp(&(y.0, y.1))));
+    pairs.truncate(options.top_k);
+    pairs.into_iter().map(|(u, v, score, shared)| similar_pair(graph, u, v, score, shared)).collect()
+}
+
+/// The `top_k` players most similar to `player`, best first.
+/// The minimum-degree filter applies to candidates, not to `player`, on both
+/// the exact and the MinHash path. None if `player` is not in the graph.
+pub fn similar_players(graph: &PlayerGraph, player: &PlayerId, options: &SimilarityOptions) -> Option<Vec<SimilarPair>> {
+    let u = find_player(graph, player)?.index();
+    let index = SimilarityIndex::new(graph, options);
+    let mut scored = index.scores_for(u, |v| v != u, &mut Scratch::new(index.adj.len()));
+    top_k(&mut scored, options.top_k);
+    Some(scored.into_iter().map(|(v, score, shared)| similar_pair(graph, u, v, score, shared)).collect())
+}
+
+/// Keeps the `k` best (neighbor, score, shared) entries, best first
+fn top_k(scored: &mut Vec<(usize, f64, usize)>, k: usize) {
+    scored.sort_by(|x, y| y.1.total_cmp(&x.1).then(x.0.cmp(&y.0)));
+    scored.truncate(k);
+}
+
+/// Wedge counts reused from one source player to the next; only the
+/// entries listed in `reached` are non-zero while a source is scored
+struct Scratch {
+    /// Shared teammates with the source and their summed Adamic–Adar weight
+    shared: Vec<(usize, f64)>,
+    reached: Vec<usize>,
+}
+
+impl Scratch {
+    fn new(n: usize) -> Self {
+        Scratch { shared: vec![(0, 0.0); n], reached: Vec::new() }
+    }
+}
+
+/// Sorted neighbor lists plus whatever the chosen method needs up front
+struct SimilarityIndex<'o> {
+    adj: Vec<Vec<usize>>,
+    /// 1 / ln(degree) per node, the Adamic–Adar weight of a shared teammate
+    rarity: Vec<f64>,
+    /// LSH candidates per node when MinHash is enabled
+    candidates: Option<Vec<Vec<usize>>>,
+    options: &'o SimilarityOptions,
+}
+
+impl<'o> SimilarityIndex<'o> {
+    fn new(graph: &PlayerGraph, options: &'o SimilarityOptions) -> Self {
+        let mut adj = adjacency(graph);
+        adj.iter_mut().for_each(|nbrs| {
+            nbrs.sort_unstable();
+            nbrs.dedup();
+        });
+        let rarity = adj
+            .iter()
+            .map(|nbrs| if nbrs.len() > 1 { 1.0 / (nbrs.len() as f64).ln() } else { 0.0 })
+            .collect();
+        let mut index = SimilarityIndex { adj, rarity, candidates: None, options };
+        if let Some(minhash) = &options.minhash {
+            index.candidates = Some(index.lsh_candidates(minhash));
+        }
+        index
+    }
+
+    fn eligible(&self, v: usize) -> bool {
+        self.adj[v].len() >= self.options.min_degree.max(1)
+    }
+
+    /// (v, score, shared) for every eligible v accepted by `keep` that shares a teammate with u.
+    /// `scratch` is left zeroed for the next source.
+    fn scores_for(&self, u: usize, keep: impl Fn(usize) -> bool, scratch: &mut Scratch) -> Vec<(usize, f64, usize)> {
+        let keep = |v: usize| keep(v) && self.eligible(v);
+        match &self.candidates {
+            Some(candidates) => candidates[u]
+                .iter()
+                .filter(|&&v| keep(v))
+                .filter_map(|&v| {
+                    let (shared, rarity) = self.intersect(u, v);
+                    (shared > 0).then(|| (v, self.score(u, v, shared, rarity), shared))
+                })
+                .collect(),
+            None => {
+                // Walk u -> w -> v wedges; each one is a shared teammate w
+                let Scratch { shared, reached } = scratch;
+                for &w in &self.adj[u] {
+                    for &v in &self.adj[w] {
+                        if keep(v) {
+                            if shared[v].0 == 0 {
+                                reached.push(v);
+                            }
+                            shared[v].0 += 1;
+                            shared[v].1 += self.rarity[w];
+                        }
+                    }
+                }
+                reached
+                    .drain(..)
+                    .map(|v| {
+                        let (count, rarity) = std::mem::take(&mut shared[v]);
+                        (v, self.score(u, v, count, rarity), count)
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Shared teammates of u and v and their summed Adamic–Adar weight (sorted-list merge)
+    fn intersect(&self, u: usize, v: usize) -> (usize, f64) {
+        let (a, b) = (&self.adj[u], &self.adj[v]);
+        let (mut i, mut j) = (0, 0);
+        let (mut shared, mut rarity) = (0, 0.0);
+        while i < a.len() && j < b.len() {
+            match a[i].cmp(&b[j]) {
+                std::cmp::Ordering::Less => i += 1,
+                std::cmp::Ordering::Greater => j += 1,
+                std::cmp::Ordering::Equal => {
+                    shared += 1;
+                    rarity += self.rarity[a[i]];
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+        (shared, rarity)
+    }
+
+    fn score(&self, u: usize, v: usize, shared: usize, rarity: f64) -> f64 {
+        let (du, dv, c) = (self.adj[u].len() as f64, self.adj[v].len() as f64, shared as f64);
+        match self.options.metric {
+            SimilarityMetric::Jaccard => c / (du + dv - c),
+            SimilarityMetric::Cosine => c / (du * dv).sqrt(),
+            SimilarityMetric::AdamicAdar => rarity,
+            SimilarityMetric::Overlap => c / du.min(dv),
+        }
+    }
+
+    /// Players sharing at least one LSH band bucket, for every player with a
+    /// teammate. Ineligible players are hashed too so a query below
+    /// `min_degree` still finds candidates; `scores_for` filters them out.
+    fn lsh_candidates(&self, minhash: &MinHashOptions) -> Vec<Vec<usize>> {
+        let n = self.adj.len();
+        let hashes = minhash.bands * minhash.rows;
+        let mut rng = StdRng::seed_from_u64(minhash.seed);
+        let salts: Vec<u64> = (0..hashes).map(|_| rng.random()).collect();
+
+        let mut candidates: Vec<Vec<usize>> = vec![Vec::new(); n];
+        for band in 0..minhash.bands {
+            let salts = &salts[band * minhash.rows..(band + 1) * minhash.rows];
+            let mut buckets: HashMap<Vec<u64>, Vec<usize>> = HashMap::new();
+            for v in (0..n).filter(|&v| !self.adj[v].is_empty()) {
+                let signature = salts
+                    .iter()
+                    .map(|&salt| self.adj[v].iter().map(|&w| mix(w as u64 ^ salt)).min().unwrap_or(u64::MAX))
+                    .collect();
+                buckets.entry(signature).or_default().push(v);
+            }
+            for bucket in buckets.values().filter(|b| b.len() > 1) {
+                for &u in bucket {
+                    candidates[u].extend(bucket.iter().copied().filter(|&v| v != u));
+                }
+            }
+        }
+        candidates.iter_mut().for_each(|c| {
+            c.sort_unstable();
+            c.dedup();
+        });
+        candidates
+    }
+}
+
+fn similar_pair(graph: &PlayerGraph, u: usize, v: usize, score: f64, shared: usize) -> SimilarPair {
+    SimilarPair { a: graph[NodeIndex::new(u)].clone(), b: graph[NodeIndex::new(v)].clone(), score, shared }
+}
+
+/// SplitMix64 finalizer: a cheap, well-mixed hash of one integer
+fn mix(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_builder::graph_from_edges;
+
+    /// a and b share all three teammates x, y, z; c only knows x
+    fn graph() -> PlayerGraph {
+        graph_from_edges(&[("a", "x"), ("a", "y"), ("a", "z"), ("b", "x"), ("b", "y"), ("b", "z"), ("c", "x")])
+    }
+
+    fn options(metric: SimilarityMetric) -> SimilarityOptions {
+        SimilarityOptions { metric, min_degree: 1, ..SimilarityOptions::default() }
+    }
+
+    fn pair(p: &SimilarPair) -> (&str, &str, usize) {
+        (p.a.as_str(), p.b.as_str(), p.shared)
+    }
+
+    #[test]
+    fn ranks_pairs_by_jaccard() {
+        let pairs = most_similar_pairs(&graph(), &options(SimilarityMetric::Jaccard));
+        assert_eq!(pair(&pairs[0]), ("a", "b", 3));
+        assert_eq!(pairs[0].score, 1.0);
+        // x has {a, b, c}, y and z have {a, b}
+        assert!(pairs[1..].iter().any(|p| pair(p) == ("x", "y", 2) && (p.score - 2.0 / 3.0).abs() < 1e-12));
+        assert!(pairs.windows(2).all(|w| w[0].score >= w[1].score));
+    }
+
+    #[test]
+    fn scores_each_metric() {
+        let top = |metric| most_similar_pairs(&graph(), &options(metric))[0].score;
+        assert_eq!(top(SimilarityMetric::Cosine), 1.0);
+        assert_eq!(top(SimilarityMetric::Overlap), 1.0);
+        let adamic_adar = 1.0 / 3f64.ln() + 2.0 / 2f64.ln();
+        assert!((top(SimilarityMetric::AdamicAdar) - adamic_adar).abs() < 1e-12);
+    }
+
+    #[test]
+    fn finds_players_similar_to_one_player() {
+        let similar = similar_players(&graph(), &PlayerId::new("c"), &options(SimilarityMetric::Jaccard)).unwrap();
+        let found: Vec<(&str, &str, usize)> = similar.iter().map(pair).collect();
+        assert_eq!(found, [("c", "a", 1), ("c", "b", 1)]);
+        assert!((similar[0].score - 1.0 / 3.0).abs() < 1e-12);
+        assert!(similar_players(&graph(), &PlayerId::new("nobody"), &options(SimilarityMetric::Jaccard)).is_none());
+
+        let strict = SimilarityOptions { min_degree: 3, ..options(SimilarityMetric::Jaccard) };
+        assert!(similar_players(&graph(), &PlayerId::new("x"), &strict).unwrap().is_empty());
+    }
+
+    #[test]
+    fn minhash_keeps_identical_teammate_sets() {
+        let lsh = SimilarityOptions { minhash: Some(MinHashOptions::default()), ..options(SimilarityMetric::Jaccard) };
+        let pairs = most_similar_pairs(&graph(), &lsh);
+        assert_eq!(pair(&pairs[0]), ("a", "b", 3));
+    }
+
+    #[test]
+    fn minhash_scores_a_query_below_min_degree_like_the_exact_path() {
+        // q (3 teammates) is below min_degree; p (4) is the only eligible candidate
+        let graph = graph_from_edges(&[("q", "x"), ("q", "y"), ("q", "z"), ("p", "x"), ("p", "y"), ("p", "z"), ("p", "w")]);
+        let exact = SimilarityOptions { min_degree: 4, ..options(SimilarityMetric::Jaccard) };
+        let lsh = SimilarityOptions { minhash: Some(MinHashOptions::default()), ..exact.clone() };
+        let found = |options| {
+            let similar = similar_players(&graph, &PlayerId::new("q"), options).unwrap();
+            similar.iter().map(|p| (p.b.to_string(), p.score)).collect::<Vec<_>>()
+        };
+        assert_eq!(found(&exact), [("p".to_string(), 0.75)]);
+        assert_eq!(found(&lsh), found(&exact));
+    }
+}
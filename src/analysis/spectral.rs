@@ -0,0 +1,171 @@
+// src/analysis/spectral.rs
+// Module: analysis::spectral
+// Purpose: Weighted PageRank and eigenvector centrality on the teammate graph by power iteration.
+
+use super::traversal::strength_adjacency;
+use crate::data_loader::PlayerId;
+use crate::graph_builder::PlayerGraph;
+use std::collections::HashMap;
+
+/// Settings for `compute_pagerank`
+#[derive(Debug, Clone)]
+pub struct PageRankOptions {
+    /// Probability of following a teammate link rather than jumping anywhere
+    pub damping: f64,
+    /// Stop once the L1 change between iterations falls below this
+    pub tolerance: f64,
+    pub max_iterations: usize,
+}
+
+impl Default for PageRankOptions {
+    fn default() -> Self {
+        PageRankOptions { damping: 0.85, tolerance: 1e-10, max_iterations: 100 }
+    }
+}
+
+/// Settings for `compute_eigenvector_centrality`
+#[derive(Debug, Clone)]
+pub struct EigenvectorOptions {
+    /// Stop once no player's score changes by more than this between iterations
+    pub tolerance: f64,
+    pub max_iterations: usize,
+}
+
+impl Default for EigenvectorOptions {
+    fn default() -> Self {
+        EigenvectorOptions { tolerance: 1e-10, max_iterations: 100 }
+    }
+}
+
+/// Pairs each node's score with its PlayerId
+fn by_player(graph: &PlayerGraph, scores: Vec<f64>) -> HashMap<PlayerId, f64> {
+    graph
+        .node_indices()
+        .map(|node| (graph[node].clone(), scores[node.index()]))
+        .collect()
+}
+
+/// PageRank using edge weights: a random walker moves to a teammate with
+/// probability proportional to their tie strength. Players without
+/// teammates spread their rank evenly. Scores sum to 1. If the tolerance is
+/// not reached within `max_iterations`, the last iterate is returned.
+pub fn compute_pagerank(graph: &PlayerGraph, options: &PageRankOptions) -> HashMap<PlayerId, f64> {
+    let n = graph.node_count();
+    if n == 0 {
+        return HashMap::new();
+    }
+    let adj = strength_adjacency(graph);
+    let strength: Vec<f64> = adj.iter().map(|nbrs| nbrs.iter().map(|(_, w)| w).sum()).collect();
+    let d = options.damping;
+
+    let mut rank = vec![1.0 / n as f64; n];
+    for _ in 0..options.max_iterations {
+        // Rank held by players with no (positive-weight) teammates
+        let dangling: f64 = (0..n).filter(|&v| strength[v] <= 0.0).map(|v| rank[v]).sum();
+        let base = (1.0 - d) / n as f64 + d * dangling / n as f64;
+
+        let mut next = vec![base; n];
+        for (u, nbrs) in adj.iter().enumerate() {
+            if strength[u] <= 0.0 {
+                continue;
+            }
+            let share = d * rank[u] / strength[u];
+            for &(v, w) in nbrs {
+                next[v] += share * w;
+            }
+        }
+
+        let change: f64 = next.iter().zip(&rank).map(|(a, b)| (a - b).abs()).sum();
+        rank = next;
+        if change < options.tolerance {
+            break;
+        }
+    }
+
+    by_player(graph, rank)
+}
+
+/// Eigenvector centrality using edge weights: a player is central when
+/// strongly tied to other central players. Power iteration on A + I (the
+/// shift avoids oscillation on near-bipartite graphs, same leading
+/// eigenvector); the result has unit Euclidean norm. If the tolerance is
+/// not reached within `max_iterations`, the last iterate is returned.
+pub fn compute_eigenvector_centrality(graph: &PlayerGraph, options: &EigenvectorOptions) -> HashMap<PlayerId, f64> {
+    let n = graph.node_count();
+    if n == 0 {
+        return HashMap::new();
+    }
+    let adj = strength_adjacency(graph);
+
+    let mut x = vec![1.0 / n as f64; n];
+    for _ in 0..options.max_iterations {
+        let mut next = x.clone();
+        for (u, nbrs) in adj.iter().enumerate() {
+            for &(v, w) in nbrs {
+                next[v] += x[u] * w;
+            }
+        }
+
+        let norm = next.iter().map(|v| v * v).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            break;
+        }
+        next.iter_mut().for_each(|v| *v /= norm);
+
+        let change = next.iter().zip(&x).map(|(a, b)| (a - b).abs()).fold(0.0, f64::max);
+        x = next;
+        if change < options.tolerance {
+            break;
+        }
+    }
+
+    by_player(graph, x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_builder::{graph_from_edges, weighted_graph_from_edges};
+
+    fn star() -> PlayerGraph {
+        graph_from_edges(&[("hub", "x"), ("hub", "y"), ("hub", "z")])
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-6
+    }
+
+    #[test]
+    fn pagerank_sums_to_one_and_favors_hubs() {
+        let cycle = graph_from_edges(&[("a", "b"), ("b", "c"), ("c", "d"), ("d", "a")]);
+        assert!(compute_pagerank(&cycle, &PageRankOptions::default()).values().all(|&r| close(r, 0.25)));
+
+        let ranks = compute_pagerank(&star(), &PageRankOptions::default());
+        assert!(close(ranks.values().sum(), 1.0));
+        // hub = 0.15/4 + 0.85 * 3 leaf and leaf = 0.15/4 + 0.85 * hub/3
+        let hub = ranks[&PlayerId::new("hub")];
+        assert!(close(hub, (0.0375 + 0.85 * 3.0 * 0.0375) / (1.0 - 0.85 * 0.85)));
+        assert!(hub > ranks[&PlayerId::new("x")]);
+        assert!(close(ranks[&PlayerId::new("x")], ranks[&PlayerId::new("z")]));
+    }
+
+    #[test]
+    fn pagerank_follows_tie_strength() {
+        // b's strong tie to a sends most of b's rank there
+        let graph = weighted_graph_from_edges(&[("a", "b", 9.0), ("b", "c", 1.0)]);
+        let ranks = compute_pagerank(&graph, &PageRankOptions::default());
+        assert!(ranks[&PlayerId::new("a")] > ranks[&PlayerId::new("c")]);
+
+        let mut with_loner = star();
+        with_loner.add_node(PlayerId::new("loner"));
+        assert!(close(compute_pagerank(&with_loner, &PageRankOptions::default()).values().sum(), 1.0));
+    }
+
+    #[test]
+    fn eigenvector_centrality_of_a_star() {
+        let scores = compute_eigenvector_centrality(&star(), &EigenvectorOptions::default());
+        // Leading eigenvector of K(1,3): hub sqrt(3) times each leaf, unit length
+        assert!(close(scores[&PlayerId::new("hub")], 1.0 / 2f64.sqrt()));
+        assert!(close(scores[&PlayerId::new("x")], 1.0 / 6f64.sqrt()));
+    }
+}
@@ -0,0 +1,211 @@
+// src/analysis/traversal.rs
+// Module: analysis::traversal
+// Purpose: Shared building blocks for graph algorithms: compact adjacency lists, breadth-first search, Dijkstra, min-degree peeling, and a scoped-thread parallel map.
+
+use crate::graph_builder::PlayerGraph;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::thread;
+
+/// Marks a node BFS has not reached
+pub(crate) const UNREACHED: u32 = u32::MAX;
+
+/// Neighbor lists indexed by node index.
+/// Much cheaper to scan repeatedly than petgraph's linked edge lists.
+pub(crate) fn adjacency(graph: &PlayerGraph) -> Vec<Vec<usize>> {
+    let mut adj = vec![Vec::new(); graph.node_count()];
+    for edge in graph.raw_edges() {
+        let (a, b) = (edge.source().index(), edge.target().index());
+        if a != b {
+            adj[a].push(b);
+            adj[b].push(a);
+        }
+    }
+    adj
+}
+
+/// Neighbor lists with each edge's path length (`TeammateEdge::distance`).
+pub(crate) fn weighted_adjacency(graph: &PlayerGraph) -> Vec<Vec<(usize, f64)>> {
+    let mut adj = vec![Vec::new(); graph.node_count()];
+    for edge in graph.raw_edges() {
+        let (a, b) = (edge.source().index(), edge.target().index());
+        if a != b {
+            let length = edge.weight.distance();
+            adj[a].push((b, length));
+            adj[b].push((a, length));
+        }
+    }
+    adj
+}
+
+/// Neighbor lists with each edge's tie strength (`TeammateEdge::weight`).
+pub(crate) fn strength_adjacency(graph: &PlayerGraph) -> Vec<Vec<(usize, f64)>> {
+    let mut adj = vec![Vec::new(); graph.node_count()];
+    for edge in graph.raw_edges() {
+        let (a, b) = (edge.source().index(), edge.target().index());
+        if a != b {
+            adj[a].push((b, edge.weight.weight));
+            adj[b].push((a, edge.weight.weight));
+        }
+    }
+    adj
+}
+
+/// Breadth-first search from `source`.
+/// Fills `dist` (hop counts, UNREACHED otherwise) and returns the nodes in visit order.
+pub(crate) fn bfs(adj: &[Vec<usize>], source: usize, dist: &mut Vec<u32>) -> Vec<usize> {
+    dist.clear();
+    dist.resize(adj.len(), UNREACHED);
+    dist[source] = 0;
+
+    let mut order = vec![source];
+    let mut head = 0;
+    while head < order.len() {
+        let u = order[head];
+        head += 1;
+        for &v in &adj[u] {
+            if dist[v] == UNREACHED {
+                dist[v] = dist[u] + 1;
+                order.push(v);
+            }
+        }
+    }
+    order
+}
+
+/// Dijkstra from `source` over `weighted_adjacency` path lengths.
+/// Fills `dist` (INFINITY when unreached) and returns the nodes in the order they settle.
+pub(crate) fn dijkstra(adj: &[Vec<(usize, f64)>], source: usize, dist: &mut Vec<f64>) -> Vec<usize> {
+    dist.clear();
+    dist.resize(adj.len(), f64::INFINITY);
+    dist[source] = 0.0;
+
+    let mut settled = vec![false; adj.len()];
+    let mut order = Vec::new();
+    // Non-negative f64s order the same as their bit patterns
+    let mut heap = BinaryHeap::from([Reverse((0f64.to_bits(), source))]);
+    while let Some(Reverse((bits, u))) = heap.pop() {
+        if settled[u] {
+            continue;
+        }
+        settled[u] = true;
+        order.push(u);
+        let d = f64::from_bits(bits);
+        for &(v, length) in &adj[u] {
+            let candidate = d + length;
+            if candidate < dist[v] {
+                dist[v] = candidate;
+                heap.push(Reverse((candidate.to_bits(), v)));
+            }
+        }
+    }
+    order
+}
+
+/// Hop distance from `source` to `target`, stopping as soon as it is found.
+/// None when the two are in different components.
+pub(crate) fn bfs_distance(adj: &[Vec<usize>], source: usize, target: usize) -> Option<u32> {
+    if source == target {
+        return Some(0);
+    }
+    let mut dist = vec![UNREACHED; adj.len()];
+    dist[source] = 0;
+    let mut queue = std::collections::VecDeque::from([source]);
+    while let Some(u) = queue.pop_front() {
+        for &v in &adj[u] {
+            if dist[v] == UNREACHED {
+                dist[v] = dist[u] + 1;
+                if v == target {
+                    return Some(dist[v]);
+                }
+                queue.push_back(v);
+            }
+        }
+    }
+    None
+}
+
+/// Removes a minimum-degree node at a time until none are left.
+/// Returns the nodes in removal order, each with its degree when removed.
+pub(crate) fn min_degree_order(adj: &[Vec<usize>]) -> Vec<(usize, usize)> {
+    let n = adj.len();
+    let mut degree: Vec<usize> = adj.iter().map(|nbrs| nbrs.len()).collect();
+    let max_degree = degree.iter().copied().max().unwrap_or(0);
+
+    // Bucket queue with lazy deletion: stale entries are skipped when popped
+    let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); max_degree + 1];
+    for (v, &d) in degree.iter().enumerate() {
+        buckets[d].push(v);
+    }
+    let mut removed = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+    let mut d = 0;
+    while order.len() < n {
+        let Some(v) = buckets[d].pop() else {
+            d += 1;
+            continue;
+        };
+        if removed[v] || degree[v] != d {
+            continue;
+        }
+        removed[v] = true;
+        order.push((v, d));
+        for &u in &adj[v] {
+            if !removed[u] {
+                degree[u] -= 1;
+                buckets[degree[u]].push(u);
+            }
+        }
+        d = d.saturating_sub(1);
+    }
+    order
+}
+
+/// Applies `f` to every item of `0..count` across all available cores.
+/// Results come back in index order.
+pub(crate) fn parallel_map<T, F>(count: usize, f: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(usize) -> T + Sync,
+{
+    let threads = thread::available_parallelism().map(|n| n.get()).unwrap_or(1).min(count.max(1));
+    let chunk = count.div_ceil(threads).max(1);
+
+    thread::scope(|scope| {
+        let f = &f;
+        let handles: Vec<_> = (0..count)
+            .step_by(chunk)
+            .map(|start| scope.spawn(move || (start..(start + chunk).min(count)).map(f).collect::<Vec<T>>()))
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().expect("analysis worker thread panicked"))
+            .collect()
+    })
+}
+
+/// Labels every node with a component id (0 = largest component).
+pub(crate) fn component_labels(adj: &[Vec<usize>]) -> Vec<usize> {
+    let mut label = vec![usize::MAX; adj.len()];
+    let mut sizes = Vec::new();
+    let mut dist = Vec::new();
+    for start in 0..adj.len() {
+        if label[start] != usize::MAX {
+            continue;
+        }
+        let members = bfs(adj, start, &mut dist);
+        for &v in &members {
+            label[v] = sizes.len();
+        }
+        sizes.push(members.len());
+    }
+
+    // Renumber so component ids follow decreasing size (ties by first node)
+    let mut by_size: Vec<usize> = (0..sizes.len()).collect();
+    by_size.sort_by(|&a, &b| sizes[b].cmp(&sizes[a]).then(a.cmp(&b)));
+    let mut rank = vec![0; sizes.len()];
+    for (new_id, &old_id) in by_size.iter().enumerate() {
+        rank[old_id] = new_id;
+    }
+    label.iter().map(|&l| rank[l]).collect()
+}
@@ -2,55 +2,635 @@
 // Module: data_loader
 // Purpose: Load NBA player-season data from CSV and convert it into structured PlayerSeason records with performance stats.
 
-use csv::ReaderBuilder;
+use csv::{ReaderBuilder, StringRecord};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::str::FromStr;
+
+mod franchise;
+mod identity;
+mod season;
+
+pub use franchise::{franchise_of, TeamGrouping, FRANCHISE_TABLE};
+pub use identity::{normalize_name, resolve_identities, IdentityOverrides, PlayerId};
+pub use season::{Season, SeasonParseError};
 
 /// Represents a player's statistics for a single season.
-/// Fields include name, team, season, points, assists, and rebounds per game.
+/// Carries every column of `all_seasons.csv`: biographical data, draft
+/// information, per-game box score averages and advanced rates.
 #[derive(Debug, Clone)]
 pub struct PlayerSeason {
+    pub player_id: PlayerId,        // resolved identity, see `resolve_identities`
     pub player_name: String,
     pub team: String,
-    pub season: String,
+    pub season: Season,
+    pub age: f64,
+    pub player_height: f64,         // centimetres
+    pub player_weight: f64,         // kilograms
+    pub college: Option<String>,    // None when the source says "None"
+    pub country: String,
+    pub draft_year: Option<u16>,    // None for "Undrafted"
+    pub draft_round: Option<u8>,    // None for "Undrafted"
+    pub draft_number: Option<u16>,  // None for "Undrafted"
+    pub gp: u32,
     pub pts: f64,
-    pub ast: f64,
     pub reb: f64,
+    pub ast: f64,
+    pub net_rating: f64,
+    pub oreb_pct: f64,
+    pub dreb_pct: f64,
+    pub usg_pct: f64,
+    pub ts_pct: f64,
+    pub ast_pct: f64,
+}
+
+impl PlayerSeason {
+    /// Team label for this row under `grouping` (abbreviation or franchise)
+    pub fn team_key(&self, grouping: TeamGrouping) -> &str {
+        grouping.team_key(&self.team)
+    }
+}
+
+/// Logical columns of the player-season schema.
+/// Each column is located in the CSV by header name rather than position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Column {
+    PlayerName,
+    Team,
+    Season,
+    Age,
+    PlayerHeight,
+    PlayerWeight,
+    College,
+    Country,
+    DraftYear,
+    DraftRound,
+    DraftNumber,
+    Gp,
+    Pts,
+    Reb,
+    Ast,
+    NetRating,
+    OrebPct,
+    DrebPct,
+    UsgPct,
+    TsPct,
+    AstPct,
+}
+
+impl Column {
+    /// Every column, in the order of the Kaggle export
+    pub const ALL: [Column; 21] = [
+        Column::PlayerName,
+        Column::Team,
+        Column::Age,
+        Column::PlayerHeight,
+        Column::PlayerWeight,
+        Column::College,
+        Column::Country,
+        Column::DraftYear,
+        Column::DraftRound,
+        Column::DraftNumber,
+        Column::Gp,
+        Column::Pts,
+        Column::Reb,
+        Column::Ast,
+        Column::NetRating,
+        Column::OrebPct,
+        Column::DrebPct,
+        Column::UsgPct,
+        Column::TsPct,
+        Column::AstPct,
+        Column::Season,
+    ];
+
+    /// True for the columns the loader cannot do without: the graph needs
+    /// name, team, season and games played, identity resolution needs age,
+    /// draft year and college. Every other column defaults when absent.
+    pub fn is_required(self) -> bool {
+        matches!(
+            self,
+            Column::PlayerName | Column::Team | Column::Season | Column::Gp | Column::Age | Column::DraftYear | Column::College
+        )
+    }
+
+    /// Canonical header name as it appears in `all_seasons.csv`
+    pub fn name(self) -> &'static str {
+        match self {
+            Column::PlayerName => "player_name",
+            Column::Team => "team_abbreviation",
+            Column::Season => "season",
+            Column::Age => "age",
+            Column::PlayerHeight => "player_height",
+            Column::PlayerWeight => "player_weight",
+            Column::College => "college",
+            Column::Country => "country",
+            Column::DraftYear => "draft_year",
+            Column::DraftRound => "draft_round",
+            Column::DraftNumber => "draft_number",
+            Column::Gp => "gp",
+            Column::Pts => "pts",
+            Column::Reb => "reb",
+            Column::Ast => "ast",
+            Column::NetRating => "net_rating",
+            Column::OrebPct => "oreb_pct",
+            Column::DrebPct => "dreb_pct",
+            Column::UsgPct => "usg_pct",
+            Column::TsPct => "ts_pct",
+            Column::AstPct => "ast_pct",
+        }
+    }
+}
+
+/// Maps each logical column to the header names accepted for it.
+/// Header matching ignores case and surrounding whitespace.
+#[derive(Debug, Clone)]
+pub struct ColumnMapping {
+    aliases: HashMap<Column, Vec<String>>,
+}
+
+impl Default for ColumnMapping {
+    /// Canonical header names plus common spellings from other exports
+    fn default() -> Self {
+        let mut mapping = ColumnMapping { aliases: HashMap::new() };
+        for column in Column::ALL {
+            mapping = mapping.with_alias(column, column.name());
+        }
+        mapping
+            .with_alias(Column::PlayerName, "player")
+            .with_alias(Column::PlayerName, "name")
+            .with_alias(Column::Team, "team")
+            .with_alias(Column::PlayerHeight, "height")
+            .with_alias(Column::PlayerWeight, "weight")
+            .with_alias(Column::Gp, "games_played")
+    }
+}
+
+impl ColumnMapping {
+    /// Accepts `alias` as an additional header name for `column`
+    pub fn with_alias(mut self, column: Column, alias: &str) -> Self {
+        let alias = alias.trim().to_lowercase();
+        let names = self.aliases.entry(column).or_default();
+        if !names.contains(&alias) {
+            names.push(alias);
+        }
+        self
+    }
+
+    /// Header names accepted for `column`
+    pub fn aliases(&self, column: Column) -> &[String] {
+        self.aliases.get(&column).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Finds the position of every column in `headers`.
+    /// Fails with the full list of required columns that could not be found;
+    /// absent optional columns are listed in the returned index.
+    pub fn resolve(&self, headers: &StringRecord) -> Result<ColumnIndex, LoadError> {
+        let normalized: Vec<String> = headers.iter().map(|h| h.trim().to_lowercase()).collect();
+        let mut positions = HashMap::new();
+        let mut absent = Vec::new();
+        let mut missing = Vec::new();
+
+        for column in Column::ALL {
+            let aliases = self.aliases(column);
+            match normalized.iter().position(|h| aliases.contains(h)) {
+                Some(pos) => {
+                    positions.insert(column, pos);
+                }
+                None if column.is_required() => missing.push(MissingColumn { column, aliases: aliases.to_vec() }),
+                None => absent.push(column),
+            }
+        }
+
+        if missing.is_empty() {
+            Ok(ColumnIndex { positions, absent })
+        } else {
+            Err(LoadError::MissingColumns(missing))
+        }
+    }
+}
+
+/// Resolved header positions for one CSV file
+#[derive(Debug, Clone)]
+pub struct ColumnIndex {
+    positions: HashMap<Column, usize>,
+    absent: Vec<Column>,
+}
+
+impl ColumnIndex {
+    /// Returns the raw value of `column` in `record`, if present
+    pub fn get<'r>(&self, record: &'r StringRecord, column: Column) -> Option<&'r str> {
+        self.positions.get(&column).and_then(|&pos| record.get(pos))
+    }
+
+    /// Optional columns no header matched, in `Column::ALL` order
+    pub fn absent(&self) -> &[Column] {
+        &self.absent
+    }
+}
+
+/// A required column that no header matched
+#[derive(Debug, Clone)]
+pub struct MissingColumn {
+    pub column: Column,
+    pub aliases: Vec<String>,
+}
+
+/// Errors that stop the loader from producing any records
+#[derive(Debug)]
+pub enum LoadError {
+    Io { path: String, source: io::Error },
+    Csv(csv::Error),
+    MissingColumns(Vec<MissingColumn>),
+    InvalidOverride { line: u64, message: String },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io { path, source } => write!(f, "cannot read {}: {}", path, source),
+            LoadError::Csv(err) => write!(f, "CSV error: {}", err),
+            LoadError::MissingColumns(missing) => {
+                let described: Vec<String> = missing
+                    .iter()
+                    .map(|m| format!("{} (accepted headers: {})", m.column.name(), m.aliases.join(", ")))
+                    .collect();
+                write!(f, "missing required column(s): {}", described.join("; "))
+            }
+            LoadError::InvalidOverride { line, message } => write!(f, "identity override line {}: {}", line, message),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadError::Io { source, .. } => Some(source),
+            LoadError::Csv(err) => Some(err),
+            LoadError::MissingColumns(_) | LoadError::InvalidOverride { .. } => None,
+        }
+    }
+}
+
+impl From<csv::Error> for LoadError {
+    fn from(err: csv::Error) -> Self {
+        LoadError::Csv(err)
+    }
+}
+
+/// Why a row was left out of the loaded records
+#[derive(Debug, Clone)]
+pub enum SkipReason {
+    /// The CSV reader could not split the row into fields
+    Malformed(String),
+    /// Name, team or season was empty
+    MissingIdentifier(Column),
+    /// One or more columns held values that could not be parsed
+    InvalidValues(Vec<Column>),
+}
+
+/// A single skipped row and the 1-based line it came from
+#[derive(Debug, Clone)]
+pub struct SkippedRow {
+    pub line: u64,
+    pub reason: SkipReason,
+}
+
+/// Data-quality summary produced alongside the loaded records
+#[derive(Debug, Clone, Default)]
+pub struct LoadReport {
+    pub rows_read: usize,
+    pub rows_loaded: usize,
+    pub skipped: Vec<SkippedRow>,
+    /// Optional columns missing from the file; their fields hold defaults
+    pub absent_columns: Vec<Column>,
+    /// Line numbers of unparseable values, per column
+    pub parse_failures: HashMap<Column, Vec<u64>>,
+}
+
+impl LoadReport {
+    /// Number of rows that did not make it into the output
+    pub fn skipped_rows(&self) -> usize {
+        self.skipped.len()
+    }
+
+    /// True when every row was loaded without parse failures
+    pub fn is_clean(&self) -> bool {
+        self.skipped.is_empty() && self.parse_failures.is_empty()
+    }
+
+    /// Line numbers where `column` failed to parse
+    pub fn failures_for(&self, column: Column) -> &[u64] {
+        self.parse_failures.get(&column).map(Vec::as_slice).unwrap_or(&[])
+    }
+}
+
+/// Reads typed values out of one record, remembering which columns failed
+struct RowParser<'a> {
+    columns: &'a ColumnIndex,
+    record: &'a StringRecord,
+    failed: Vec<Column>,
+}
+
+impl<'a> RowParser<'a> {
+    fn text(&self, column: Column) -> &'a str {
+        self.columns.get(self.record, column).unwrap_or("").trim()
+    }
+
+    /// True when the file has a header for `column`
+    fn present(&self, column: Column) -> bool {
+        !self.columns.absent.contains(&column)
+    }
+
+    /// Parses a numeric column; failures are recorded rather than coerced.
+    /// An absent optional column reads as zero.
+    fn number<T: FromStr + Default>(&mut self, column: Column) -> T {
+        if !self.present(column) {
+            return T::default();
+        }
+        match self.text(column).parse::<T>() {
+            Ok(value) => value,
+            Err(_) => {
+                self.failed.push(column);
+                T::default()
+            }
+        }
+    }
+
+    /// Parses a draft column; "Undrafted" and absent columns become None
+    fn draft<T: FromStr>(&mut self, column: Column) -> Option<T> {
+        let raw = self.text(column);
+        if !self.present(column) || raw.eq_ignore_ascii_case("undrafted") {
+            return None;
+        }
+        match raw.parse::<T>() {
+            Ok(value) => Some(value),
+            Err(_) => {
+                self.failed.push(column);
+                None
+            }
+        }
+    }
+
+    /// Parses and validates the season column
+    fn season(&mut self) -> Season {
+        match self.text(Column::Season).parse::<Season>() {
+            Ok(season) => season,
+            Err(_) => {
+                self.failed.push(Column::Season);
+                Season::new(0)
+            }
+        }
+    }
+
+    /// Parses the college column; "None" and empty strings become None
+    fn college(&self) -> Option<String> {
+        match self.text(Column::College) {
+            "" | "None" => None,
+            college => Some(college.to_string()),
+        }
+    }
 }
 
-/// Loads the CSV file and returns a vector of PlayerSeason records.
-/// Filters out any rows missing name, team, or season.
-pub fn load_players(path: &str) -> Vec<PlayerSeason> {
+/// Loads the CSV file using the default column mapping.
+pub fn load_players(path: &str) -> Result<Vec<PlayerSeason>, LoadError> {
+    load_players_with_mapping(path, &ColumnMapping::default())
+}
+
+/// Loads the CSV file with a custom column mapping and no identity
+/// overrides, discarding the load report.
+pub fn load_players_with_mapping(path: &str, mapping: &ColumnMapping) -> Result<Vec<PlayerSeason>, LoadError> {
+    load_players_with_report(path, mapping, &IdentityOverrides::default()).map(|(players, _)| players)
+}
+
+/// Loads the CSV file and returns PlayerSeason records plus a LoadReport.
+/// Columns are located by header name through `mapping`. Rows with empty
+/// identifiers or unparseable values are skipped and listed in the report.
+/// Player identities are resolved with `overrides` applied.
+pub fn load_players_with_report(
+    path: &str,
+    mapping: &ColumnMapping,
+    overrides: &IdentityOverrides,
+) -> Result<(Vec<PlayerSeason>, LoadReport), LoadError> {
+    // Open the file ourselves so a missing path is reported as such
+    let file = File::open(path).map_err(|source| LoadError::Io { path: path.to_string(), source })?;
+
     // Initialize CSV reader with headers enabled
     let mut rdr = ReaderBuilder::new()
         .has_headers(true)
-        .from_path(path)
-        .expect("Cannot open CSV file");
+        .from_reader(file);
+
+    // Resolve every column by header before reading any rows
+    let columns = mapping.resolve(rdr.headers()?)?;
 
     let mut players = Vec::new();
+    let mut report = LoadReport { absent_columns: columns.absent().to_vec(), ..LoadReport::default() };
 
-    // Iterate over each row in the CSV
     for result in rdr.records() {
-        if let Ok(record) = result {
-            // Extract relevant fields by column index
-            let player_name = record.get(1).unwrap_or("").to_string(); // player name
-            let team = record.get(2).unwrap_or("").to_string();        // team abbreviation
-            let season = record.get(21).unwrap_or("").to_string();     // season
-            let pts = record.get(12).unwrap_or("0.0").parse::<f64>().unwrap_or(0.0); // points per game
-            let reb = record.get(13).unwrap_or("0.0").parse::<f64>().unwrap_or(0.0); // rebounds per game
-            let ast = record.get(14).unwrap_or("0.0").parse::<f64>().unwrap_or(0.0); // assists per game
-
-            // Only include rows with non-empty identifiers
-            if !player_name.is_empty() && !team.is_empty() && !season.is_empty() {
-                players.push(PlayerSeason {
-                    player_name,
-                    team,
-                    season,
-                    pts,
-                    ast,
-                    reb,
-                });
-            }
-        }
-    }
-
-    players
+        report.rows_read += 1;
+
+        let record = match result {
+            Ok(record) => record,
+            Err(err) => {
+                let line = err.position().map(|p| p.line()).unwrap_or(0);
+                report.skipped.push(SkippedRow { line, reason: SkipReason::Malformed(err.to_string()) });
+                continue;
+            }
+        };
+        let line = record.position().map(|p| p.line()).unwrap_or(0);
+
+        let mut row = RowParser { columns: &columns, record: &record, failed: Vec::new() };
+
+        // Only include rows with non-empty identifiers
+        let missing_id = [Column::PlayerName, Column::Team, Column::Season]
+            .into_iter()
+            .find(|&column| row.text(column).is_empty());
+        if let Some(column) = missing_id {
+            report.skipped.push(SkippedRow { line, reason: SkipReason::MissingIdentifier(column) });
+            continue;
+        }
+
+        let player = PlayerSeason {
+            player_id: PlayerId::new(row.text(Column::PlayerName)),
+            player_name: row.text(Column::PlayerName).to_string(),
+            team: row.text(Column::Team).to_string(),
+            season: row.season(),
+            age: row.number(Column::Age),
+            player_height: row.number(Column::PlayerHeight),
+            player_weight: row.number(Column::PlayerWeight),
+            college: row.college(),
+            country: row.text(Column::Country).to_string(),
+            draft_year: row.draft(Column::DraftYear),
+            draft_round: row.draft(Column::DraftRound),
+            draft_number: row.draft(Column::DraftNumber),
+            gp: row.number(Column::Gp),
+            pts: row.number(Column::Pts),
+            reb: row.number(Column::Reb),
+            ast: row.number(Column::Ast),
+            net_rating: row.number(Column::NetRating),
+            oreb_pct: row.number(Column::OrebPct),
+            dreb_pct: row.number(Column::DrebPct),
+            usg_pct: row.number(Column::UsgPct),
+            ts_pct: row.number(Column::TsPct),
+            ast_pct: row.number(Column::AstPct),
+        };
+
+        // Never publish rows with coerced values; record them instead
+        if !row.failed.is_empty() {
+            for &column in &row.failed {
+                report.parse_failures.entry(column).or_default().push(line);
+            }
+            report.skipped.push(SkippedRow { line, reason: SkipReason::InvalidValues(row.failed) });
+            continue;
+        }
+
+        players.push(player);
+    }
+
+    resolve_identities(&mut players, overrides);
+
+    report.rows_loaded = players.len();
+    Ok((players, report))
+}
+
+/// A player-season row for unit tests; every stat not given is zero
+#[cfg(test)]
+pub(crate) fn test_row(name: &str, team: &str, season: &str) -> PlayerSeason {
+    PlayerSeason {
+        player_id: PlayerId::new(name),
+        player_name: name.to_string(),
+        team: team.to_string(),
+        season: season.parse().expect("test season"),
+        age: 25.0,
+        player_height: 0.0,
+        player_weight: 0.0,
+        college: None,
+        country: "USA".to_string(),
+        draft_year: None,
+        draft_round: None,
+        draft_number: None,
+        gp: 82,
+        pts: 0.0,
+        reb: 0.0,
+        ast: 0.0,
+        net_rating: 0.0,
+        oreb_pct: 0.0,
+        dreb_pct: 0.0,
+        usg_pct: 0.0,
+        ts_pct: 0.0,
+        ast_pct: 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &str = "player_name,team_abbreviation,age,player_height,player_weight,college,country,draft_year,draft_round,draft_number,gp,pts,reb,ast,net_rating,oreb_pct,dreb_pct,usg_pct,ts_pct,ast_pct,season";
+
+    /// Writes `contents` to a per-test file in the temp directory and returns its path
+    fn write_csv(name: &str, contents: &str) -> String {
+        let path = std::env::temp_dir().join(format!("nba_network_{}_{}.csv", std::process::id(), name));
+        std::fs::write(&path, contents).expect("write test CSV");
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn loads_rows_with_optional_fields() {
+        let path = write_csv(
+            "valid",
+            &format!(
+                "{}\n\
+                 Vince Carter,TOR,22,198.12,99.8,North Carolina,USA,1998,1,5,50,18.3,5.7,3.0,3.1,0.05,0.12,0.28,0.55,0.15,1998-99\n\
+                 Ben Wallace,DET,26,205.74,108.9,None,USA,Undrafted,Undrafted,Undrafted,80,6.4,13.2,1.7,5.0,0.1,0.3,0.12,0.5,0.08,2000-01\n",
+                HEADER
+            ),
+        );
+        let (players, report) = load_players_with_report(&path, &ColumnMapping::default(), &IdentityOverrides::default()).unwrap();
+        assert!(report.is_clean());
+        assert_eq!((report.rows_read, report.rows_loaded), (2, 2));
+
+        let carter = &players[0];
+        assert_eq!(carter.player_id, PlayerId::new("Vince Carter"));
+        assert_eq!((carter.team.as_str(), carter.season), ("TOR", Season::new(1998)));
+        assert_eq!((carter.draft_year, carter.draft_round, carter.draft_number), (Some(1998), Some(1), Some(5)));
+        assert_eq!(carter.college.as_deref(), Some("North Carolina"));
+        assert_eq!(carter.gp, 50);
+
+        let wallace = &players[1];
+        assert_eq!((wallace.draft_year, wallace.college.as_deref()), (None, None));
+    }
+
+    #[test]
+    fn skips_and_reports_bad_rows() {
+        let path = write_csv(
+            "invalid",
+            &format!(
+                "{}\n\
+                 Good Player,BOS,24,200,100,None,USA,2000,1,10,70,10,5,2,1,0.1,0.2,0.2,0.5,0.1,2001-02\n\
+                 Bad Games,BOS,24,200,100,None,USA,2000,1,10,seventy,10,5,2,1,0.1,0.2,0.2,0.5,0.1,2001-02\n\
+                 ,BOS,24,200,100,None,USA,2000,1,10,70,10,5,2,1,0.1,0.2,0.2,0.5,0.1,2001-02\n\
+                 Bad Season,BOS,24,200,100,None,USA,2000,1,10,70,10,5,2,1,0.1,0.2,0.2,0.5,0.1,2001-03\n",
+                HEADER
+            ),
+        );
+        let (players, report) = load_players_with_report(&path, &ColumnMapping::default(), &IdentityOverrides::default()).unwrap();
+        assert_eq!(players.len(), 1);
+        assert_eq!((report.rows_read, report.rows_loaded, report.skipped_rows()), (4, 1, 3));
+        assert_eq!(report.failures_for(Column::Gp), [3]);
+        assert_eq!(report.failures_for(Column::Season), [5]);
+        assert!(matches!(report.skipped[1].reason, SkipReason::MissingIdentifier(Column::PlayerName)));
+    }
+
+    #[test]
+    fn applies_identity_overrides_while_loading() {
+        let path = write_csv(
+            "overrides",
+            &format!(
+                "{}\n\
+                 Mike James,BOS,29,200,100,None,USA,Undrafted,Undrafted,Undrafted,70,10,5,2,1,0.1,0.2,0.2,0.5,0.1,2004-05\n\
+                 Michael James,TOR,30,200,100,None,USA,Undrafted,Undrafted,Undrafted,70,10,5,2,1,0.1,0.2,0.2,0.5,0.1,2005-06\n",
+                HEADER
+            ),
+        );
+        let overrides = IdentityOverrides::default().with_rule("Michael James", None, "Mike James");
+        let (players, _) = load_players_with_report(&path, &ColumnMapping::default(), &overrides).unwrap();
+        assert!(players.iter().all(|p| p.player_id == PlayerId::new("Mike James")));
+    }
+
+    #[test]
+    fn matches_headers_by_alias_and_lists_missing_columns() {
+        let renamed = HEADER.replace("player_name", " Player ").replace("gp", "games_played");
+        let path = write_csv(
+            "aliases",
+            &format!("{}\nA,BOS,24,200,100,None,USA,2000,1,10,70,10,5,2,1,0.1,0.2,0.2,0.5,0.1,2001-02\n", renamed),
+        );
+        let players = load_players(&path).unwrap();
+        assert_eq!((players[0].player_name.as_str(), players[0].gp), ("A", 70));
+
+        let path = write_csv("missing", "player_name,season,pts\nA,2001-02,10\n");
+        match load_players(&path) {
+            Err(LoadError::MissingColumns(missing)) => {
+                let columns: Vec<Column> = missing.iter().map(|m| m.column).collect();
+                assert_eq!(columns, [Column::Team, Column::Age, Column::College, Column::DraftYear, Column::Gp]);
+            }
+            other => panic!("expected missing columns, got {:?}", other.map(|p| p.len())),
+        }
+    }
+
+    #[test]
+    fn defaults_absent_optional_columns() {
+        let path = write_csv(
+            "optional",
+            "player_name,team_abbreviation,age,college,draft_year,gp,season\nA,BOS,24,None,2000,70,2001-02\n",
+        );
+        let (players, report) = load_players_with_report(&path, &ColumnMapping::default(), &IdentityOverrides::default()).unwrap();
+        assert!(report.is_clean());
+        assert_eq!(report.absent_columns.len(), Column::ALL.len() - 7);
+        assert!(report.absent_columns.contains(&Column::DraftRound));
+        let a = &players[0];
+        assert_eq!((a.gp, a.draft_year, a.draft_round, a.pts, a.country.as_str()), (70, Some(2000), None, 0.0, ""));
+    }
 }
@@ -0,0 +1,60 @@
+// src/data_loader/franchise.rs
+// Module: data_loader::franchise
+// Purpose: Map historical team abbreviations to their current franchise so relocated and renamed teams can be grouped together.
+
+/// Historical abbreviation -> current franchise abbreviation.
+/// Abbreviations not listed here already name their franchise.
+/// The Charlotte Hornets of 1988-2002 (CHH) are grouped with the team that
+/// moved to New Orleans, following the players rather than the NBA's later
+/// transfer of the Hornets records back to Charlotte.
+pub const FRANCHISE_TABLE: &[(&str, &str)] = &[
+    ("SEA", "OKC"), // Seattle SuperSonics -> Oklahoma City Thunder (2008)
+    ("NJN", "BKN"), // New Jersey Nets -> Brooklyn Nets (2012)
+    ("VAN", "MEM"), // Vancouver Grizzlies -> Memphis Grizzlies (2001)
+    ("CHH", "NOP"), // Charlotte Hornets -> New Orleans (2002)
+    ("NOH", "NOP"), // New Orleans Hornets -> Pelicans (2013)
+    ("NOK", "NOP"), // New Orleans/Oklahoma City Hornets (2005-07)
+];
+
+/// Returns the current franchise abbreviation for `abbreviation`
+pub fn franchise_of(abbreviation: &str) -> &str {
+    FRANCHISE_TABLE
+        .iter()
+        .find(|(historical, _)| *historical == abbreviation)
+        .map(|(_, current)| *current)
+        .unwrap_or(abbreviation)
+}
+
+/// How team abbreviations are grouped when building graphs and reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TeamGrouping {
+    /// Use the abbreviation exactly as it appears in the data
+    #[default]
+    Abbreviation,
+    /// Collapse relocated and renamed teams into their current franchise
+    Franchise,
+}
+
+impl TeamGrouping {
+    /// The team label used for `abbreviation` under this grouping
+    pub fn team_key(self, abbreviation: &str) -> &str {
+        match self {
+            TeamGrouping::Abbreviation => abbreviation,
+            TeamGrouping::Franchise => franchise_of(abbreviation),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_relocated_teams_to_their_franchise() {
+        assert_eq!(franchise_of("VAN"), "MEM");
+        assert_eq!(franchise_of("CHH"), "NOP");
+        assert_eq!(franchise_of("LAL"), "LAL");
+        assert_eq!(TeamGrouping::Franchise.team_key("SEA"), "OKC");
+        assert_eq!(TeamGrouping::Abbreviation.team_key("SEA"), "SEA");
+    }
+}
@@ -0,0 +1,367 @@
+// src/data_loader/identity.rs
+// Module: data_loader::identity
+// Purpose: Resolve player-season rows to stable player identities so players sharing a name are kept apart and spelling variants are merged.
+
+use super::{LoadError, PlayerSeason, Season};
+use csv::ReaderBuilder;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+
+/// Stable identifier for one real player.
+/// Equals the player's name unless another player shares it, in which case
+/// a draft-year or birth-year qualifier is appended, e.g. "Glen Rice (2013 draft)".
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
+pub struct PlayerId(String);
+
+impl PlayerId {
+    pub fn new(id: impl Into<String>) -> Self {
+        PlayerId(id.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for PlayerId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Manual identity assignments that take precedence over the heuristics.
+/// Giving two names the same id merges them; giving some seasons of a name
+/// a different id splits them off.
+#[derive(Debug, Clone, Default)]
+pub struct IdentityOverrides {
+    rules: Vec<OverrideRule>,
+}
+
+/// One line of the override file
+#[derive(Debug, Clone)]
+struct OverrideRule {
+    player_name: String,
+    season: Option<Season>, // None applies to every season of the name
+    player_id: PlayerId,
+}
+
+impl IdentityOverrides {
+    /// Reads an override CSV with headers `player_name,season,player_id`.
+    /// An empty or `*` season applies the rule to every season of that name.
+    pub fn load(path: &str) -> Result<Self, LoadError> {
+        let file = File::open(path).map_err(|source| LoadError::Io { path: path.to_string(), source })?;
+        let mut rdr = ReaderBuilder::new()
+            .has_headers(true)
+            .comment(Some(b'#'))
+            .from_reader(file);
+
+        let headers: Vec<String> = rdr.headers()?.iter().map(|h| h.trim().to_lowercase()).collect();
+        let position = |name: &str| headers.iter().position(|h| h == name);
+        let (Some(name_col), Some(id_col)) = (position("player_name"), position("player_id")) else {
+            return Err(LoadError::InvalidOverride {
+                line: 1,
+                message: "override file needs player_name and player_id headers".to_string(),
+            });
+        };
+        let season_col = position("season");
+
+        let mut rules = Vec::new();
+        for result in rdr.records() {
+            let record = result?;
+            let line = record.position().map(|p| p.line()).unwrap_or(0);
+            let player_name = record.get(name_col).unwrap_or("").trim();
+            let player_id = record.get(id_col).unwrap_or("").trim();
+            if player_name.is_empty() || player_id.is_empty() {
+                return Err(LoadError::InvalidOverride {
+                    line,
+                    message: "player_name and player_id must not be empty".to_string(),
+                });
+            }
+            let season = match season_col.and_then(|col| record.get(col)).map(str::trim) {
+                None | Some("") | Some("*") => None,
+                Some(raw) => Some(raw.parse::<Season>().map_err(|err| LoadError::InvalidOverride {
+                    line,
+                    message: err.to_string(),
+                })?),
+            };
+
+            rules.push(OverrideRule {
+                player_name: player_name.to_string(),
+                season,
+                player_id: PlayerId::new(player_id),
+            });
+        }
+
+        Ok(IdentityOverrides { rules })
+    }
+
+    /// Adds a rule assigning `player_id` to `player_name` (optionally in one season only)
+    pub fn with_rule(mut self, player_name: &str, season: Option<Season>, player_id: &str) -> Self {
+        self.rules.push(OverrideRule {
+            player_name: player_name.to_string(),
+            season,
+            player_id: PlayerId::new(player_id),
+        });
+        self
+    }
+
+    /// Returns the overriding id for a row, preferring season-specific rules
+    fn lookup(&self, player: &PlayerSeason) -> Option<&PlayerId> {
+        let matching = |rule: &&OverrideRule| rule.player_name == player.player_name;
+        self.rules
+            .iter()
+            .filter(matching)
+            .find(|rule| rule.season == Some(player.season))
+            .or_else(|| self.rules.iter().filter(matching).find(|rule| rule.season.is_none()))
+            .map(|rule| &rule.player_id)
+    }
+}
+
+/// Folds spelling variants together: case, punctuation and
+/// generational suffixes ("Jr.", "III") are ignored.
+pub fn normalize_name(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .filter(|c| !matches!(c, '.' | '\'' | '`'))
+        .map(|c| if c == '-' { ' ' } else { c.to_ascii_lowercase() })
+        .collect();
+    let words: Vec<&str> = cleaned
+        .split_whitespace()
+        .filter(|w| !matches!(*w, "jr" | "sr" | "ii" | "iii" | "iv"))
+        .collect();
+    words.join(" ")
+}
+
+/// Approximate birth year implied by a row's age and season start
+fn birth_year(player: &PlayerSeason) -> f64 {
+    player.season.start_year() as f64 - player.age
+}
+
+/// Rows believed to belong to one person while resolving a name group
+struct Cluster {
+    rows: Vec<usize>,
+    draft_year: Option<u16>,
+    birth_sum: f64,
+    colleges: Vec<String>,
+}
+
+impl Cluster {
+    fn mean_birth(&self) -> f64 {
+        self.birth_sum / self.rows.len() as f64
+    }
+
+    /// A row joins a cluster when implied birth years agree and draft years
+    /// do not conflict. A missing draft year on either side is tolerated
+    /// because the source marks some drafted players "Undrafted" in some seasons.
+    /// The source has one row per player per season, so a cluster never
+    /// takes a second row from the same season.
+    fn accepts(&self, player: &PlayerSeason, players: &[PlayerSeason]) -> bool {
+        if self.rows.iter().any(|&row| players[row].season == player.season) {
+            return false;
+        }
+        let draft_compatible = match (self.draft_year, player.draft_year) {
+            (Some(a), Some(b)) => a == b,
+            _ => true,
+        };
+        draft_compatible && (self.mean_birth() - birth_year(player)).abs() <= 2.0
+    }
+
+    /// True when the cluster already lists the row's college
+    fn shares_college(&self, player: &PlayerSeason) -> bool {
+        player.college.as_ref().is_some_and(|c| self.colleges.contains(c))
+    }
+
+    fn push(&mut self, row: usize, player: &PlayerSeason) {
+        self.rows.push(row);
+        self.birth_sum += birth_year(player);
+        self.draft_year = self.draft_year.or(player.draft_year);
+        if let Some(college) = &player.college
+            && !self.colleges.contains(college)
+        {
+            self.colleges.push(college.clone());
+        }
+    }
+
+    /// Qualifier used when two clusters would otherwise share a label
+    fn qualifier(&self) -> String {
+        match self.draft_year {
+            Some(year) => format!("{} draft", year),
+            None => format!("undrafted, b. {}", self.mean_birth().round() as i64),
+        }
+    }
+}
+
+/// Assigns a PlayerId to every row.
+/// Rows are grouped by normalized name, split by draft year and implied
+/// birth year (college breaks ties), then labelled with their most common
+/// spelling. Override rules are applied last and always win.
+pub fn resolve_identities(players: &mut [PlayerSeason], overrides: &IdentityOverrides) {
+    // Group rows by normalized name, in first-seen order for stable output
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut group_of: HashMap<String, usize> = HashMap::new();
+    for (i, p) in players.iter().enumerate() {
+        let key = normalize_name(&p.player_name);
+        let g = *group_of.entry(key).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[g].push(i);
+    }
+
+    // Split each name group into per-person clusters, oldest seasons first
+    let mut clusters: Vec<Cluster> = Vec::new();
+    for mut rows in groups {
+        rows.sort_by(|&a, &b| players[a].season.cmp(&players[b].season));
+        let first = clusters.len();
+        for row in rows {
+            let p = &players[row];
+            // Among compatible clusters, prefer one with the same college
+            let candidates = clusters[first..].iter_mut().filter(|c| c.accepts(p, players));
+            match candidates.max_by_key(|c| c.shares_college(p)) {
+                Some(cluster) => cluster.push(row, p),
+                None => {
+                    let mut cluster = Cluster {
+                        rows: Vec::new(),
+                        draft_year: p.draft_year,
+                        birth_sum: 0.0,
+                        colleges: Vec::new(),
+                    };
+                    cluster.push(row, p);
+                    clusters.push(cluster);
+                }
+            }
+        }
+    }
+
+    // Label each cluster with its most common raw spelling
+    let labels: Vec<String> = clusters
+        .iter()
+        .map(|c| {
+            let mut counts: HashMap<&str, usize> = HashMap::new();
+            for &row in &c.rows {
+                *counts.entry(players[row].player_name.as_str()).or_insert(0) += 1;
+            }
+            let mut spellings: Vec<_> = counts.into_iter().collect();
+            spellings.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+            spellings[0].0.to_string()
+        })
+        .collect();
+
+    // Qualify labels shared by more than one cluster
+    let mut label_count: HashMap<&str, usize> = HashMap::new();
+    for label in &labels {
+        *label_count.entry(label.as_str()).or_insert(0) += 1;
+    }
+    let mut ids: Vec<String> = clusters
+        .iter()
+        .zip(&labels)
+        .map(|(c, label)| {
+            if label_count[label.as_str()] > 1 {
+                format!("{} ({})", label, c.qualifier())
+            } else {
+                label.clone()
+            }
+        })
+        .collect();
+
+    // Last resort for clusters that are still indistinguishable
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    for id in ids.iter_mut() {
+        let n = seen.entry(id.clone()).or_insert(0);
+        *n += 1;
+        if *n > 1 {
+            *id = format!("{} #{}", id, n);
+        }
+    }
+
+    for (cluster, id) in clusters.iter().zip(ids) {
+        for &row in &cluster.rows {
+            players[row].player_id = PlayerId::new(id.clone());
+        }
+    }
+
+    // Manual overrides take precedence over every heuristic
+    for p in players.iter_mut() {
+        if let Some(id) = overrides.lookup(p) {
+            p.player_id = id.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_row;
+
+    /// A row with the fields the identity heuristics look at
+    fn row(name: &str, season: &str, age: f64, draft_year: Option<u16>) -> PlayerSeason {
+        PlayerSeason { age, draft_year, ..test_row(name, "LAL", season) }
+    }
+
+    fn ids(players: &[PlayerSeason]) -> Vec<&str> {
+        players.iter().map(|p| p.player_id.as_str()).collect()
+    }
+
+    #[test]
+    fn normalizes_case_punctuation_and_suffixes() {
+        assert_eq!(normalize_name("Gary Payton Jr."), "gary payton");
+        assert_eq!(normalize_name("Marvin Bagley III"), "marvin bagley");
+        assert_eq!(normalize_name("Shaquille O'Neal"), "shaquille oneal");
+        assert_eq!(normalize_name("Karl-Anthony  Towns"), "karl anthony towns");
+    }
+
+    #[test]
+    fn splits_players_sharing_a_name_by_draft_year() {
+        let mut players = vec![
+            row("Glen Rice", "1996-97", 29.0, Some(1989)),
+            row("Glen Rice", "1997-98", 30.0, Some(1989)),
+            row("Glen Rice", "2013-14", 23.0, Some(2013)),
+        ];
+        resolve_identities(&mut players, &IdentityOverrides::default());
+        assert_eq!(ids(&players), ["Glen Rice (1989 draft)", "Glen Rice (1989 draft)", "Glen Rice (2013 draft)"]);
+    }
+
+    #[test]
+    fn keeps_father_and_son_apart_under_their_own_spellings() {
+        let mut players = vec![
+            row("Tim Hardaway", "1996-97", 30.0, Some(1989)),
+            row("Tim Hardaway Jr.", "2013-14", 21.0, Some(2013)),
+        ];
+        resolve_identities(&mut players, &IdentityOverrides::default());
+        assert_eq!(ids(&players), ["Tim Hardaway", "Tim Hardaway Jr."]);
+    }
+
+    #[test]
+    fn merges_suffix_variants_of_one_player() {
+        let mut players = vec![
+            row("Marvin Bagley", "2018-19", 19.0, Some(2018)),
+            row("Marvin Bagley III", "2019-20", 20.0, Some(2018)),
+            row("Marvin Bagley III", "2020-21", 21.0, None), // source lists some drafted players as undrafted
+        ];
+        resolve_identities(&mut players, &IdentityOverrides::default());
+        assert_eq!(ids(&players), ["Marvin Bagley III"; 3]);
+    }
+
+    #[test]
+    fn never_merges_two_rows_from_one_season() {
+        let mut players = vec![row("John Smith", "2000-01", 25.0, None), row("John Smith", "2000-01", 25.0, None)];
+        resolve_identities(&mut players, &IdentityOverrides::default());
+        assert_ne!(players[0].player_id, players[1].player_id);
+    }
+
+    #[test]
+    fn overrides_win_and_prefer_season_specific_rules() {
+        let mut players = vec![
+            row("Mike James", "2004-05", 29.0, None),
+            row("Mike James", "2005-06", 30.0, None),
+            row("Michael James", "2006-07", 31.0, None),
+        ];
+        let overrides = IdentityOverrides::default()
+            .with_rule("Michael James", None, "Mike James")
+            .with_rule("Mike James", Some(Season::new(2005)), "Mike James (split)");
+        resolve_identities(&mut players, &overrides);
+        assert_eq!(ids(&players), ["Mike James", "Mike James (split)", "Mike James"]);
+    }
+}
@@ -0,0 +1,148 @@
+// src/data_loader/season.rs
+// Module: data_loader::season
+// Purpose: Parsed NBA season type ("1996-97") with ordering, display, and inclusive season ranges.
+
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// One NBA season, identified by the calendar year it starts in.
+/// Orders chronologically and displays as "1996-97".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Season {
+    start_year: u16,
+}
+
+impl Season {
+    pub fn new(start_year: u16) -> Self {
+        Season { start_year }
+    }
+
+    /// Calendar year the season starts in (1996 for "1996-97")
+    pub fn start_year(self) -> u16 {
+        self.start_year
+    }
+
+    /// Calendar year the season ends in (1997 for "1996-97")
+    pub fn end_year(self) -> u16 {
+        self.start_year + 1
+    }
+
+    /// The following season; None past the last representable year
+    pub fn next(self) -> Option<Season> {
+        self.advance(1)
+    }
+
+    /// The season `n` years later; None if its start year overflows a u16
+    pub fn advance(self, n: u16) -> Option<Season> {
+        self.start_year.checked_add(n).map(Season::new)
+    }
+
+    /// Parses an inclusive range such as "1996-97..=2005-06".
+    /// A single season ("2001-02") yields a one-season range.
+    pub fn parse_range(s: &str) -> Result<RangeInclusive<Season>, SeasonParseError> {
+        match s.split_once("..=") {
+            Some((start, end)) => {
+                let (start, end): (Season, Season) = (start.parse()?, end.parse()?);
+                if start > end {
+                    return Err(SeasonParseError(format!("range {} ends before it starts", s)));
+                }
+                Ok(start..=end)
+            }
+            None => {
+                let season: Season = s.parse()?;
+                Ok(season..=season)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Season {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{:02}", self.start_year, self.end_year() % 100)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Season {
+    /// Serializes as the display form, "1996-97"
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// A season string that is not of the form "YYYY-YY"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeasonParseError(pub String);
+
+impl fmt::Display for SeasonParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid season: {}", self.0)
+    }
+}
+
+impl std::error::Error for SeasonParseError {}
+
+impl FromStr for Season {
+    type Err = SeasonParseError;
+
+    /// Accepts "1996-97" and "1999-2000"; the end year must follow the start year
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SeasonParseError(s.to_string());
+        let (start, end) = s.trim().split_once('-').ok_or_else(invalid)?;
+        if start.len() != 4 || !(end.len() == 2 || end.len() == 4) {
+            return Err(invalid());
+        }
+        let start_year: u16 = start.parse().map_err(|_| invalid())?;
+        let end_year: u16 = end.parse().map_err(|_| invalid())?;
+
+        let expected = start_year + 1;
+        let matches = if end.len() == 2 { end_year == expected % 100 } else { end_year == expected };
+        if !matches {
+            return Err(invalid());
+        }
+        Ok(Season::new(start_year))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_two_and_four_digit_end_years() {
+        assert_eq!("1996-97".parse(), Ok(Season::new(1996)));
+        assert_eq!("1999-00".parse(), Ok(Season::new(1999)));
+        assert_eq!("1999-2000".parse(), Ok(Season::new(1999)));
+        assert_eq!(" 2009-10 ".parse(), Ok(Season::new(2009)));
+    }
+
+    #[test]
+    fn rejects_malformed_seasons() {
+        for bad in ["", "1996", "1996-98", "1996-1998", "96-97", "abcd-ef", "1996-9x", "1996/97"] {
+            assert!(bad.parse::<Season>().is_err(), "{:?} should not parse", bad);
+        }
+    }
+
+    #[test]
+    fn displays_and_orders_chronologically() {
+        assert_eq!(Season::new(1996).to_string(), "1996-97");
+        assert_eq!(Season::new(1999).to_string(), "1999-00");
+        assert!(Season::new(1999) < Season::new(1999).next().unwrap());
+        assert_eq!(Season::new(1996).advance(4).unwrap().to_string(), "2000-01");
+        assert_eq!(Season::new(2000).advance(u16::MAX), None);
+    }
+
+    #[test]
+    fn parses_inclusive_ranges() {
+        let range = Season::parse_range("1996-97..=1998-99").unwrap();
+        assert_eq!((*range.start(), *range.end()), (Season::new(1996), Season::new(1998)));
+        assert!(range.contains(&Season::new(1997)));
+
+        let single = Season::parse_range("2001-02").unwrap();
+        assert_eq!(single, Season::new(2001)..=Season::new(2001));
+
+        assert!(Season::parse_range("2005-06..=1996-97").is_err());
+        assert!(Season::parse_range("1996-97..=later").is_err());
+    }
+}
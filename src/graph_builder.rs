@@ -3,58 +3,520 @@
 // Purpose: Constructs the player network graph where nodes represent players and edges represent shared team-season membership.
 
 use petgraph::graph::{Graph, NodeIndex};
+use petgraph::visit::EdgeRef;
 use petgraph::Undirected;
 use std::collections::HashMap;
-use crate::data_loader::PlayerSeason;
+use std::fmt;
+use std::ops::RangeInclusive;
+use crate::data_loader::{normalize_name, PlayerId, PlayerSeason, Season, TeamGrouping};
 
 /// Type alias for the graph used across the project
-/// Each node is a player (String), and edges count number of shared team-season stints
-pub type PlayerGraph = Graph<String, usize, Undirected>;
+/// Each node is a player (PlayerId), and each edge lists the team-seasons the two players shared
+pub type PlayerGraph = Graph<PlayerId, TeammateEdge, Undirected>;
+
+/// One team-season shared by two teammates
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SharedStint {
+    pub season: Season,
+    /// Abbreviation the team played under that season (e.g. NJN), even when rosters are grouped by franchise
+    pub team: String,
+    pub games: u32, // overlap estimate: the smaller of the two players' games played
+}
+
+impl fmt::Display for SharedStint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.team, self.season)
+    }
+}
+
+/// Edge data: every team-season the two players shared, in chronological order,
+/// plus the tie strength under the graph's EdgeWeighting
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TeammateEdge {
+    pub stints: Vec<SharedStint>,
+    pub weight: f64,
+}
+
+impl TeammateEdge {
+    /// Number of shared team-season stints
+    pub fn count(&self) -> usize {
+        self.stints.len()
+    }
+
+    /// Path length for weighted shortest paths: strong ties are short
+    pub fn distance(&self) -> f64 {
+        if self.weight > 0.0 { 1.0 / self.weight } else { f64::INFINITY }
+    }
+}
+
+/// How strongly two teammates are tied, summed over their shared stints
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EdgeWeighting {
+    /// 1 per shared team-season
+    #[default]
+    SharedStints,
+    /// Estimated games played together: min(gp) per shared team-season
+    GamesOverlap,
+    /// Games overlap scaled by the pair's mean usage rate, so two
+    /// rotation players count more than a starter and a benchwarmer
+    CombinedUsage,
+}
+
+impl EdgeWeighting {
+    /// Contribution of one shared stint between players `a` and `b`
+    fn stint_weight(self, a: &RosterMember, b: &RosterMember) -> f64 {
+        let games = a.gp.min(b.gp) as f64;
+        match self {
+            EdgeWeighting::SharedStints => 1.0,
+            EdgeWeighting::GamesOverlap => games,
+            EdgeWeighting::CombinedUsage => games * (a.usg_pct + b.usg_pct) / 2.0,
+        }
+    }
+}
+
+/// Looks up the node of player `id`
+pub fn find_player(graph: &PlayerGraph, id: &PlayerId) -> Option<NodeIndex> {
+    graph.node_indices().find(|&n| graph[n] == *id)
+}
+
+/// Why a typed name could not be matched to exactly one player
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlayerLookupError {
+    NotFound(String),
+    /// Several players share the name, e.g. "Tony Mitchell (2012 draft)" and "Tony Mitchell (2013 draft)"
+    Ambiguous { name: String, candidates: Vec<PlayerId> },
+}
+
+impl fmt::Display for PlayerLookupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlayerLookupError::NotFound(name) => write!(f, "no player named \"{}\"", name),
+            PlayerLookupError::Ambiguous { name, candidates } => {
+                let listed: Vec<String> = candidates.iter().map(|id| id.to_string()).collect();
+                write!(f, "\"{}\" matches {} players: {}", name, candidates.len(), listed.join(", "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for PlayerLookupError {}
+
+/// Resolves a typed name to a player in the graph.
+/// An exact id wins; otherwise the name is compared ignoring case,
+/// punctuation, suffixes ("Jr.") and disambiguation qualifiers ("(2012 draft)").
+pub fn resolve_player(graph: &PlayerGraph, name: &str) -> Result<PlayerId, PlayerLookupError> {
+    let exact = PlayerId::new(name.trim());
+    if find_player(graph, &exact).is_some() {
+        return Ok(exact);
+    }
+
+    let wanted = normalize_name(name);
+    let mut candidates: Vec<PlayerId> = graph
+        .node_weights()
+        .filter(|id| {
+            let label = id.as_str();
+            let base = label.find(" (").or_else(|| label.find(" #")).map_or(label, |end| &label[..end]);
+            normalize_name(base) == wanted
+        })
+        .cloned()
+        .collect();
+    candidates.sort();
+    match candidates.len() {
+        0 => Err(PlayerLookupError::NotFound(name.to_string())),
+        1 => Ok(candidates.remove(0)),
+        _ => Err(PlayerLookupError::Ambiguous { name: name.to_string(), candidates }),
+    }
+}
+
+/// When and where two players were teammates; None if they never were
+pub fn shared_stints<'g>(graph: &'g PlayerGraph, a: &PlayerId, b: &PlayerId) -> Option<&'g [SharedStint]> {
+    let edge = graph.find_edge(find_player(graph, a)?, find_player(graph, b)?)?;
+    Some(&graph[edge].stints)
+}
+
+/// Settings controlling how player-season rows become a graph
+#[derive(Debug, Clone, Default)]
+pub struct GraphOptions {
+    /// Group teams by literal abbreviation or by franchise
+    pub grouping: TeamGrouping,
+    /// How edge weights are computed from shared stints
+    pub weighting: EdgeWeighting,
+}
 
 /// Builds a player graph based on shared team and season
 /// Input: slice of PlayerSeason structs
 /// Output: PlayerGraph
 pub fn build_player_graph(players: &[PlayerSeason]) -> PlayerGraph {
+    build_player_graph_with(players, &GraphOptions::default())
+}
+
+/// Builds a player graph using explicit GraphOptions
+pub fn build_player_graph_with(players: &[PlayerSeason], options: &GraphOptions) -> PlayerGraph {
+    build_from_rows(players, options)
+}
+
+/// Builds a player graph from the seasons inside `seasons` only
+/// e.g. `Season::parse_range("1996-97..=2005-06")`
+pub fn build_player_graph_for_seasons(
+    players: &[PlayerSeason],
+    seasons: &RangeInclusive<Season>,
+    options: &GraphOptions,
+) -> PlayerGraph {
+    build_from_rows(players.iter().filter(|ps| seasons.contains(&ps.season)), options)
+}
+
+/// A graph built from one window of consecutive seasons
+#[derive(Debug, Clone)]
+pub struct WindowedGraph {
+    pub seasons: RangeInclusive<Season>,
+    pub graph: PlayerGraph,
+}
+
+/// Builds one graph per sliding window of `window_len` seasons, advancing
+/// `step` seasons at a time across the seasons present in `players`.
+/// e.g. window_len = 5, step = 1 gives 1996-97..=2000-01, 1997-98..=2001-02, ...
+/// Only full-length windows are returned, except when the data spans fewer
+/// than `window_len` seasons, in which case a single window covers it all.
+pub fn build_windowed_graphs(
+    players: &[PlayerSeason],
+    window_len: u16,
+    step: u16,
+    options: &GraphOptions,
+) -> Vec<WindowedGraph> {
+    let (Some(first), Some(last)) = (
+        players.iter().map(|ps| ps.season).min(),
+        players.iter().map(|ps| ps.season).max(),
+    ) else {
+        return Vec::new();
+    };
+    let window_len = window_len.max(1);
+    let step = step.max(1);
+
+    let mut windows = Vec::new();
+    let mut start = first;
+    loop {
+        // A window reaching past the last representable season ends at `last`
+        let end = start.advance(window_len - 1).map_or(last, |end| end.min(last));
+        let seasons = start..=end;
+        let graph = build_player_graph_for_seasons(players, &seasons, options);
+        windows.push(WindowedGraph { seasons, graph });
+
+        // Stop once a window reaches the final season, or the next one would not fit
+        if end >= last {
+            break;
+        }
+        match start.advance(step) {
+            Some(next) if next.advance(window_len - 1).is_some_and(|end| end <= last) => start = next,
+            _ => break,
+        }
+    }
+    windows
+}
+
+/// Node of the bipartite model: a player or one team's roster in one season
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BipartiteNode {
+    Player(PlayerId),
+    /// `team` is the grouping key; `abbreviation` is what the team was called that season
+    TeamSeason { team: String, abbreviation: String, season: Season },
+}
+
+/// Bipartite player / team-season graph
+/// Each edge says "this player was on this roster"; players never link to players
+pub type BipartiteGraph = Graph<BipartiteNode, Membership, Undirected>;
+
+/// Edge data of the bipartite graph: the player's line for that team-season
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Membership {
+    pub gp: u32,
+    pub usg_pct: f64,
+}
+
+/// Builds the bipartite player / team-season graph from the same rows as `build_player_graph`
+/// Rosters are grouped by `options.grouping`
+pub fn build_bipartite_graph(players: &[PlayerSeason], options: &GraphOptions) -> BipartiteGraph {
+    let mut graph = BipartiteGraph::new_undirected();
+    let mut player_nodes: HashMap<&PlayerId, NodeIndex> = HashMap::new();
+    let mut roster_nodes: HashMap<(&str, Season), NodeIndex> = HashMap::new();
+
+    for ps in players {
+        let player = *player_nodes
+            .entry(&ps.player_id)
+            .or_insert_with(|| graph.add_node(BipartiteNode::Player(ps.player_id.clone())));
+        let team = ps.team_key(options.grouping);
+        let roster = *roster_nodes.entry((team, ps.season)).or_insert_with(|| {
+            graph.add_node(BipartiteNode::TeamSeason {
+                team: team.to_string(),
+                abbreviation: ps.team.clone(),
+                season: ps.season,
+            })
+        });
+        graph.add_edge(player, roster, Membership { gp: ps.gp, usg_pct: ps.usg_pct });
+    }
+
+    graph
+}
+
+/// Projects a bipartite graph onto its players
+/// Two players are linked once per shared team-season, reproducing
+/// `build_player_graph_with` for the same grouping and `weighting`
+pub fn project_players(bipartite: &BipartiteGraph, weighting: EdgeWeighting) -> PlayerGraph {
     let mut graph = PlayerGraph::new_undirected();
-    let mut node_indices: HashMap<String, NodeIndex> = HashMap::new();
-    let mut teammates_map: HashMap<(String, String), usize> = HashMap::new();
 
-    // Group players by (team, season) to find co-teammates
-    let mut team_season_map: HashMap<(String, String), Vec<String>> = HashMap::new();
+    // Player nodes keep their relative order from the bipartite graph
+    let mut projected: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+    for node in bipartite.node_indices() {
+        if let BipartiteNode::Player(id) = &bipartite[node] {
+            projected.insert(node, graph.add_node(id.clone()));
+        }
+    }
+
+    let rosters: Vec<Roster> = bipartite
+        .node_indices()
+        .filter_map(|node| match &bipartite[node] {
+            BipartiteNode::TeamSeason { abbreviation, season, .. } => Some(Roster {
+                stint: SharedStint { season: *season, team: abbreviation.clone(), games: 0 },
+                members: bipartite
+                    .edges(node)
+                    .map(|e| {
+                        let player = if e.source() == node { e.target() } else { e.source() };
+                        RosterMember { node: projected[&player], gp: e.weight().gp, usg_pct: e.weight().usg_pct }
+                    })
+                    .collect(),
+            }),
+            BipartiteNode::Player(_) => None,
+        })
+        .collect();
+    connect_rosters(&mut graph, &rosters, weighting);
+
+    graph
+}
+
+/// Shared graph construction over any selection of rows.
+/// Players are interned to node indices and teams to integers up front, so
+/// the pair loop only touches integers.
+fn build_from_rows<'a>(players: impl IntoIterator<Item = &'a PlayerSeason>, options: &GraphOptions) -> PlayerGraph {
+    let mut graph = PlayerGraph::new_undirected();
+    let mut node_indices: HashMap<&'a PlayerId, NodeIndex> = HashMap::new();
+    let mut team_ids: HashMap<&'a str, u32> = HashMap::new();
+
+    // Group players by (team, season) to find co-teammates, remembering the abbreviation played under
+    let mut rosters: HashMap<(u32, Season), (&'a str, Vec<RosterMember>)> = HashMap::new();
     for ps in players {
-        team_season_map
-            .entry((ps.team.clone(), ps.season.clone()))
-            .or_default()
-            .push(ps.player_name.clone());
-    }
-
-    // For each (team, season) group, connect each pair of players
-    for (_team_season, player_list) in team_season_map {
-        for i in 0..player_list.len() {
-            let p1 = &player_list[i];
-            // Add node to graph if not already present
-            let idx1 = *node_indices.entry(p1.clone()).or_insert_with(|| graph.add_node(p1.clone()));
-
-            for j in i + 1..player_list.len() {
-                let p2 = &player_list[j];
-                let idx2 = *node_indices.entry(p2.clone()).or_insert_with(|| graph.add_node(p2.clone()));
-
-                // Ensure (p1, p2) order is canonical to avoid duplicate entries
-                let key = if p1 < p2 { (p1.clone(), p2.clone()) } else { (p2.clone(), p1.clone()) };
-                let count = teammates_map.entry(key.clone()).or_insert(0);
-                *count += 1;
-
-                // Add edge or increment existing edge weight
-                if graph.find_edge(idx1, idx2).is_none() {
-                    graph.add_edge(idx1, idx2, 1);
-                } else {
-                    let edge = graph.find_edge(idx1, idx2).unwrap();
-                    let edge_weight = graph.edge_weight_mut(edge).unwrap();
-                    *edge_weight += 1;
+        let node = *node_indices
+            .entry(&ps.player_id)
+            .or_insert_with(|| graph.add_node(ps.player_id.clone()));
+        let next_team = team_ids.len() as u32;
+        let team = *team_ids.entry(ps.team_key(options.grouping)).or_insert(next_team);
+        rosters
+            .entry((team, ps.season))
+            .or_insert_with(|| (ps.team.as_str(), Vec::new()))
+            .1
+            .push(RosterMember { node, gp: ps.gp, usg_pct: ps.usg_pct });
+    }
+
+    let rosters: Vec<Roster> = rosters
+        .into_iter()
+        .map(|((_, season), (abbreviation, members))| Roster {
+            stint: SharedStint { season, team: abbreviation.to_string(), games: 0 },
+            members,
+        })
+        .collect();
+    connect_rosters(&mut graph, &rosters, options.weighting);
+
+    graph
+}
+
+/// One player on a roster, with the stats edge weighting needs
+struct RosterMember {
+    node: NodeIndex,
+    gp: u32,
+    usg_pct: f64,
+}
+
+/// One team-season roster as graph nodes
+struct Roster {
+    stint: SharedStint, // games is filled in per pair
+    members: Vec<RosterMember>,
+}
+
+/// Running totals for one player pair while rosters are scanned
+#[derive(Default)]
+struct PairTotals {
+    rosters: Vec<(u32, u32)>, // (roster index, overlap games)
+    weight: f64,
+}
+
+/// Links every pair of players that share a roster.
+/// Shared rosters are accumulated in a map keyed by canonical
+/// (lower, higher) node pair, and the edges are added in a single pass
+/// at the end.
+fn connect_rosters(graph: &mut PlayerGraph, rosters: &[Roster], weighting: EdgeWeighting) {
+    let mut shared: HashMap<(u32, u32), PairTotals> = HashMap::new();
+    for (r, roster) in rosters.iter().enumerate() {
+        for (i, a) in roster.members.iter().enumerate() {
+            for b in &roster.members[i + 1..] {
+                if a.node == b.node {
+                    continue;
                 }
+                let key = (
+                    a.node.index().min(b.node.index()) as u32,
+                    a.node.index().max(b.node.index()) as u32,
+                );
+                let totals = shared.entry(key).or_default();
+                totals.rosters.push((r as u32, a.gp.min(b.gp)));
+                totals.weight += weighting.stint_weight(a, b);
             }
         }
     }
 
+    // Sort so edge indices do not depend on hash order
+    let mut edges: Vec<_> = shared.into_iter().collect();
+    edges.sort_unstable_by_key(|(key, _)| *key);
+    graph.reserve_edges(edges.len());
+    for ((a, b), PairTotals { rosters: shared_rosters, weight }) in edges {
+        let mut stints: Vec<SharedStint> = shared_rosters
+            .iter()
+            .map(|&(r, games)| SharedStint { games, ..rosters[r as usize].stint.clone() })
+            .collect();
+        stints.sort();
+        graph.add_edge(NodeIndex::new(a as usize), NodeIndex::new(b as usize), TeammateEdge { stints, weight });
+    }
+}
+
+/// Hand-built graph for unit tests: players in first-seen order, one weight-1 edge per pair
+#[cfg(test)]
+pub(crate) fn graph_from_edges(edges: &[(&str, &str)]) -> PlayerGraph {
+    let weighted: Vec<(&str, &str, f64)> = edges.iter().map(|&(a, b)| (a, b, 1.0)).collect();
+    weighted_graph_from_edges(&weighted)
+}
+
+/// Like `graph_from_edges`, with an explicit weight per edge
+#[cfg(test)]
+pub(crate) fn weighted_graph_from_edges(edges: &[(&str, &str, f64)]) -> PlayerGraph {
+    let mut graph = PlayerGraph::new_undirected();
+    let mut nodes: HashMap<&str, NodeIndex> = HashMap::new();
+    for &(a, b, weight) in edges {
+        let a = *nodes.entry(a).or_insert_with(|| graph.add_node(PlayerId::new(a)));
+        let b = *nodes.entry(b).or_insert_with(|| graph.add_node(PlayerId::new(b)));
+        graph.add_edge(a, b, TeammateEdge { stints: Vec::new(), weight });
+    }
     graph
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_row;
+
+    fn games(name: &str, team: &str, season: &str, gp: u32) -> PlayerSeason {
+        PlayerSeason { gp, ..test_row(name, team, season) }
+    }
+
+    fn id(name: &str) -> PlayerId {
+        PlayerId::new(name)
+    }
+
+    /// Every edge as (lower id, higher id) -> (stints, weight), for comparing graphs
+    fn edge_map(graph: &PlayerGraph) -> HashMap<(PlayerId, PlayerId), (Vec<SharedStint>, f64)> {
+        graph
+            .edge_references()
+            .map(|e| {
+                let (a, b) = (graph[e.source()].clone(), graph[e.target()].clone());
+                ((a.clone().min(b.clone()), a.max(b)), (e.weight().stints.clone(), e.weight().weight))
+            })
+            .collect()
+    }
+
+    fn rows() -> Vec<PlayerSeason> {
+        vec![
+            games("Vince Carter", "TOR", "1998-99", 50),
+            games("Charles Oakley", "TOR", "1998-99", 50),
+            games("Tracy McGrady", "TOR", "1998-99", 49),
+            games("Vince Carter", "TOR", "1999-00", 82),
+            games("Tracy McGrady", "TOR", "1999-00", 79),
+            games("Vince Carter", "NJN", "2005-06", 79),
+            games("Jeff McInnis", "NJN", "2005-06", 20),
+        ]
+    }
+
+    #[test]
+    fn links_players_sharing_a_team_season() {
+        let graph = build_player_graph(&rows());
+        assert_eq!((graph.node_count(), graph.edge_count()), (4, 4));
+
+        let stints = shared_stints(&graph, &id("Vince Carter"), &id("Tracy McGrady")).unwrap();
+        let labels: Vec<String> = stints.iter().map(|s| s.to_string()).collect();
+        assert_eq!(labels, ["TOR 1998-99", "TOR 1999-00"]);
+        assert_eq!(stints.iter().map(|s| s.games).collect::<Vec<_>>(), [49, 79]);
+        assert!(shared_stints(&graph, &id("Charles Oakley"), &id("Jeff McInnis")).is_none());
+    }
+
+    #[test]
+    fn weights_edges_by_the_chosen_scheme() {
+        let options = |weighting| GraphOptions { weighting, ..GraphOptions::default() };
+        let weight = |graph: &PlayerGraph| {
+            let edge = graph.find_edge(find_player(graph, &id("Vince Carter")).unwrap(), find_player(graph, &id("Tracy McGrady")).unwrap());
+            graph[edge.unwrap()].weight
+        };
+        assert_eq!(weight(&build_player_graph_with(&rows(), &options(EdgeWeighting::SharedStints))), 2.0);
+        assert_eq!(weight(&build_player_graph_with(&rows(), &options(EdgeWeighting::GamesOverlap))), 49.0 + 79.0);
+    }
+
+    #[test]
+    fn franchise_grouping_keeps_the_abbreviation_played_under() {
+        let options = GraphOptions { grouping: TeamGrouping::Franchise, ..GraphOptions::default() };
+        let graph = build_player_graph_with(&rows(), &options);
+        let stints = shared_stints(&graph, &id("Vince Carter"), &id("Jeff McInnis")).unwrap();
+        assert_eq!(stints[0].to_string(), "NJN 2005-06");
+
+        let projected = project_players(&build_bipartite_graph(&rows(), &options), options.weighting);
+        let stints = shared_stints(&projected, &id("Vince Carter"), &id("Jeff McInnis")).unwrap();
+        assert_eq!(stints[0].team, "NJN");
+    }
+
+    #[test]
+    fn bipartite_projection_reproduces_the_player_graph() {
+        for weighting in [EdgeWeighting::SharedStints, EdgeWeighting::GamesOverlap, EdgeWeighting::CombinedUsage] {
+            let options = GraphOptions { grouping: TeamGrouping::Franchise, weighting };
+            let bipartite = build_bipartite_graph(&rows(), &options);
+            // 4 players + 3 team-seasons, one edge per row
+            assert_eq!((bipartite.node_count(), bipartite.edge_count()), (7, 7));
+            assert_eq!(edge_map(&project_players(&bipartite, weighting)), edge_map(&build_player_graph_with(&rows(), &options)));
+        }
+    }
+
+    #[test]
+    fn builds_sliding_season_windows() {
+        let windows = build_windowed_graphs(&rows(), 2, 1, &GraphOptions::default());
+        let spans: Vec<String> = windows.iter().map(|w| format!("{}..={}", w.seasons.start(), w.seasons.end())).collect();
+        assert_eq!(spans, ["1998-99..=1999-00", "1999-00..=2000-01", "2000-01..=2001-02", "2001-02..=2002-03", "2002-03..=2003-04", "2003-04..=2004-05", "2004-05..=2005-06"]);
+        assert_eq!(windows[0].graph.edge_count(), 3);
+        assert_eq!(windows[1].graph.edge_count(), 1);
+
+        let short = build_windowed_graphs(&rows(), 20, 1, &GraphOptions::default());
+        assert_eq!(short.len(), 1);
+        assert_eq!(short[0].graph.edge_count(), 4);
+
+        // Steps and lengths past the last representable season end the sweep
+        assert_eq!(build_windowed_graphs(&rows(), 2, u16::MAX, &GraphOptions::default()).len(), 1);
+        assert_eq!(build_windowed_graphs(&rows(), u16::MAX, u16::MAX, &GraphOptions::default()).len(), 1);
+    }
+
+    #[test]
+    fn resolves_typed_names() {
+        let graph = graph_from_edges(&[
+            ("Vince Carter", "Tony Mitchell (2012 draft)"),
+            ("Vince Carter", "Tony Mitchell (2013 draft)"),
+        ]);
+        assert_eq!(resolve_player(&graph, "Vince Carter"), Ok(id("Vince Carter")));
+        assert_eq!(resolve_player(&graph, "vince  carter"), Ok(id("Vince Carter")));
+        assert_eq!(resolve_player(&graph, "Tony Mitchell (2013 draft)"), Ok(id("Tony Mitchell (2013 draft)")));
+        assert!(matches!(
+            resolve_player(&graph, "Tony Mitchell"),
+            Err(PlayerLookupError::Ambiguous { candidates, .. }) if candidates.len() == 2
+        ));
+        assert_eq!(resolve_player(&graph, "Vince"), Err(PlayerLookupError::NotFound("Vince".to_string())));
+    }
+}
@@ -2,21 +2,67 @@
 // Module: intro_view
 // Purpose: Print high-level dataset summaries and final analytical results to the console.
 
-use crate::data_loader::PlayerSeason;
-use std::collections::HashSet;
+use crate::data_loader::{LoadReport, PlayerId, PlayerSeason, SkipReason, TeamGrouping};
+use crate::analysis::{connected_components, Clustering, Components, CoreDecomposition, DenseSubgraph, DistanceStats, Partition, PathSample, PowerLawFit, RosterContinuity, SimilarPair, TeammatePath};
+use crate::graph_builder::{SharedStint, WindowedGraph};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Prints the loader's data-quality report
+/// Lists skipped rows and per-column parse failures with line numbers
+pub fn show_load_report(report: &LoadReport) {
+    println!("\n===== Data Load Report =====");
+    println!("Rows read: {}", report.rows_read);
+    println!("Rows loaded: {}", report.rows_loaded);
+    println!("Rows skipped: {}", report.skipped_rows());
+
+    if report.is_clean() {
+        println!("No data-quality problems found.");
+    }
+    if !report.absent_columns.is_empty() {
+        let names: Vec<_> = report.absent_columns.iter().map(|c| c.name()).collect();
+        println!("Absent optional columns (defaulted): {}", names.join(", "));
+    }
+
+    // Show the first few skipped rows with their reason
+    for skipped in report.skipped.iter().take(10) {
+        let reason = match &skipped.reason {
+            SkipReason::Malformed(msg) => format!("malformed row ({})", msg),
+            SkipReason::MissingIdentifier(column) => format!("empty {}", column.name()),
+            SkipReason::InvalidValues(columns) => {
+                let names: Vec<_> = columns.iter().map(|c| c.name()).collect();
+                format!("unparseable {}", names.join(", "))
+            }
+        };
+        println!("  line {}: {}", skipped.line, reason);
+    }
+
+    // Per-column failure counts, sorted by column name for stable output
+    let mut failures: Vec<_> = report.parse_failures.iter().collect();
+    failures.sort_by_key(|(column, _)| column.name());
+    for (column, lines) in failures {
+        let sample: Vec<String> = lines.iter().take(5).map(|l| l.to_string()).collect();
+        println!("  {}: {} parse failure(s), lines {}", column.name(), lines.len(), sample.join(", "));
+    }
+    println!("============================");
+}
 
 /// Prints unique player/team/season counts and sample records
-pub fn show_intro(players: &[PlayerSeason]) {
+/// Team counts follow `grouping`; sample rows show the team as recorded
+pub fn show_intro(players: &[PlayerSeason], grouping: TeamGrouping) {
     // Count unique players, teams, and seasons
-    let unique_players: HashSet<_> = players.iter().map(|p| &p.player_name).collect();
-    let unique_teams: HashSet<_> = players.iter().map(|p| &p.team).collect();
+    let unique_players: HashSet<_> = players.iter().map(|p| &p.player_id).collect();
+    let unique_teams: HashSet<_> = players.iter().map(|p| p.team_key(grouping)).collect();
+    let unique_abbreviations: HashSet<_> = players.iter().map(|p| &p.team).collect();
     let unique_seasons: HashSet<_> = players.iter().map(|p| &p.season).collect();
 
     println!("\n===== NBA Dataset Overview =====");
     println!("Total player-season records: {}", players.len());
     println!("Unique players: {}", unique_players.len());
-    println!("Unique teams: {}", unique_teams.len());
+    println!("Unique teams: {} ({} abbreviations, grouped by {:?})", unique_teams.len(), unique_abbreviations.len(), grouping);
     println!("Seasons covered: {}", unique_seasons.len());
+    if let (Some(first), Some(last)) = (unique_seasons.iter().min(), unique_seasons.iter().max()) {
+        println!("Season range: {} to {}", first, last);
+    }
     println!("Sample players:");
 
     // Print first 5 player-season records
@@ -26,39 +72,281 @@ pub fn show_intro(players: &[PlayerSeason]) {
     println!("================================\n");
 }
 
+/// Prints node, edge and density figures for each season window
+/// Shows how league connectivity changes from era to era
+pub fn show_window_summary(windows: &[WindowedGraph]) {
+    println!("===== Connectivity by Season Window =====");
+    for window in windows {
+        let n = window.graph.node_count() as f64;
+        let m = window.graph.edge_count() as f64;
+        // Density = 2m / (n(n-1)) for an undirected simple graph
+        let density = if n > 1.0 { 2.0 * m / (n * (n - 1.0)) } else { 0.0 };
+        let components = connected_components(&window.graph);
+        println!(
+            "  {}..={}: {} players, {} edges, density {:.4}, {} component(s), giant {:.1}%",
+            window.seasons.start(),
+            window.seasons.end(),
+            window.graph.node_count(),
+            window.graph.edge_count(),
+            density,
+            components.count(),
+            components.giant_fraction() * 100.0
+        );
+    }
+    println!("=========================================\n");
+}
+
+/// Prints league-average roster continuity per season
+/// plus the most and least stable rosters overall
+pub fn show_roster_continuity(continuity: &[RosterContinuity]) {
+    println!("===== Roster Continuity (returning players) =====");
+
+    // Average continuity across teams for each season
+    let mut by_season: BTreeMap<_, Vec<f64>> = BTreeMap::new();
+    for c in continuity {
+        by_season.entry(c.season).or_default().push(c.continuity);
+    }
+    for (season, values) in &by_season {
+        let avg = values.iter().sum::<f64>() / values.len() as f64;
+        println!("  {}: {:.1}% average continuity", season, avg * 100.0);
+    }
+
+    let mut ranked: Vec<_> = continuity.iter().collect();
+    ranked.sort_by(|a, b| b.continuity.partial_cmp(&a.continuity).unwrap());
+    if let (Some(most), Some(least)) = (ranked.first(), ranked.last()) {
+        println!("Most stable: {} {} ({}/{} returning)", most.team, most.season, most.returning, most.roster_size);
+        println!("Least stable: {} {} ({}/{} returning)", least.team, least.season, least.returning, least.roster_size);
+    }
+    println!("=================================================\n");
+}
+
+/// Prints the `n` highest-scoring players of any per-player metric
+pub fn show_top_scores(title: &str, scores: &HashMap<PlayerId, f64>, n: usize) {
+    let mut ranked: Vec<_> = scores.iter().collect();
+    ranked.sort_by(|a, b| b.1.partial_cmp(a.1).unwrap().then_with(|| a.0.cmp(b.0)));
+    println!("{}:", title);
+    for (id, score) in ranked.into_iter().take(n) {
+        println!("  {}: {}", id, significant(*score, 4));
+    }
+}
+
+/// Formats `value` to `digits` significant digits, so tiny scores like
+/// PageRank (~0.002) stay distinguishable
+fn significant(value: f64, digits: i32) -> String {
+    if value == 0.0 || !value.is_finite() {
+        return format!("{}", value);
+    }
+    let decimals = (digits - 1 - value.abs().log10().floor() as i32).clamp(0, 12);
+    format!("{:.*}", decimals as usize, value)
+}
+
+/// Prints the component count, giant component share, and component size distribution
+pub fn show_components(components: &Components) {
+    println!(
+        "Connected components: {} (giant component: {} players, {:.1}% of all)",
+        components.count(),
+        components.giant_size(),
+        components.giant_fraction() * 100.0
+    );
+    let distribution: Vec<String> = components
+        .size_distribution()
+        .iter()
+        .rev()
+        .map(|(size, count)| format!("{} x {}", count, size))
+        .collect();
+    println!("  component sizes (count x players): {}", distribution.join(", "));
+}
+
+/// Prints exact distance statistics for the largest components
+pub fn show_distance_stats(stats: &DistanceStats) {
+    println!("Connected components: {}", stats.components.len());
+    for (i, c) in stats.components.iter().take(3).enumerate() {
+        println!(
+            "  component {}: {} players, diameter {}, radius {}, average path {:.3}",
+            i, c.size, c.diameter, c.radius, c.average_path_length
+        );
+    }
+}
+
+/// Prints when and where two players were teammates
+pub fn show_shared_stints(a: &PlayerId, b: &PlayerId, stints: Option<&[SharedStint]>) {
+    match stints {
+        Some(stints) => {
+            let listed: Vec<String> = stints.iter().map(|s| s.to_string()).collect();
+            println!("{} and {} were teammates {} time(s): {}", a, b, stints.len(), listed.join(", "));
+        }
+        None => println!("{} and {} were never teammates", a, b),
+    }
+}
+
+/// Prints teammate chains between two players, one line per hop with the linking team-seasons
+pub fn show_teammate_paths(a: &PlayerId, b: &PlayerId, paths: &[TeammatePath]) {
+    if paths.is_empty() {
+        println!("{} and {} are not connected by any chain of teammates", a, b);
+        return;
+    }
+    for (i, path) in paths.iter().enumerate() {
+        let names: Vec<String> = path.players.iter().map(|id| id.to_string()).collect();
+        println!("Path {} ({} hop(s)): {}", i + 1, path.len(), names.join(" -> "));
+        for hop in &path.hops {
+            let stints: Vec<String> = hop.stints.iter().map(|s| s.to_string()).collect();
+            println!("  {} & {}: {}", hop.from, hop.to, stints.join(", "));
+        }
+    }
+}
+
+/// Prints the sampled average shortest path with its uncertainty
+pub fn show_path_sample(sample: &PathSample) {
+    match sample.average_length {
+        Some(avg) => {
+            println!(
+                "Average shortest path over {} sampled pairs ({} connected, {} disconnected): {:.4}",
+                sample.pairs_sampled, sample.connected_pairs, sample.disconnected_pairs, avg
+            );
+            if let (Some(se), Some((lo, hi))) = (sample.std_error, sample.confidence_95) {
+                println!("  standard error {:.4}, 95% CI [{:.4}, {:.4}]", se, lo, hi);
+            }
+        }
+        None => println!("No valid paths found in sample."),
+    }
+}
+
+/// Prints ranked similar pairs with their score and shared teammate count
+pub fn show_similar_pairs(title: &str, pairs: &[SimilarPair]) {
+    println!("{}:", title);
+    if pairs.is_empty() {
+        println!("  no eligible players share a teammate");
+    }
+    for p in pairs {
+        println!("  {} and {}: {:.4} ({} shared teammates)", p.a, p.b, p.score, p.shared);
+    }
+}
+
+/// Prints the community count, modularity, and the `n` largest communities
+/// with their most strongly tied players
+pub fn show_communities(title: &str, partition: &Partition, n: usize) {
+    println!("{}: {} communities, modularity {:.3}", title, partition.communities.len(), partition.modularity);
+    for (i, members) in partition.communities.iter().take(n).enumerate() {
+        let leaders: Vec<String> = members.iter().take(5).map(|id| id.to_string()).collect();
+        println!("  #{} ({} players): {}", i, members.len(), leaders.join(", "));
+    }
+}
+
+/// Prints a dense player group, naming its `n` best-connected members
+pub fn show_dense_subgraph(dense: &DenseSubgraph, n: usize) {
+    println!(
+        "Densest subgraph: {} players, {} teammate links, density {:.3}",
+        dense.players.len(), dense.edges, dense.density
+    );
+    for row in dense.players.iter().take(n).collect::<Vec<_>>().chunks(5) {
+        let names: Vec<String> = row.iter().map(|id| id.to_string()).collect();
+        println!("  {}", names.join(", "));
+    }
+    if dense.players.len() > n {
+        println!("  ... and {} more", dense.players.len() - n);
+    }
+}
+
+/// Prints triangle count, transitivity and average clustering next to the random-graph baseline
+pub fn show_clustering(clustering: &Clustering) {
+    println!("Triangles: {}", clustering.total_triangles);
+    println!("Global transitivity: {:.4}", clustering.transitivity);
+    println!(
+        "Average clustering: {:.4} (random graph of the same density: {:.4}, {:.1}x higher)",
+        clustering.average_clustering,
+        clustering.random_expectation,
+        if clustering.random_expectation > 0.0 { clustering.average_clustering / clustering.random_expectation } else { 0.0 }
+    );
+}
+
+/// Prints the fitted power law and whether the bootstrap test rejects it
+pub fn show_power_law(fit: Option<&PowerLawFit>) {
+    println!("===== Power-Law Fit (Degree Distribution) =====");
+    let Some(fit) = fit else {
+        println!("Too few players to fit a power law.");
+        println!("===============================================");
+        return;
+    };
+    println!("alpha = {:.3} ± {:.3}, x_min = {}", fit.alpha, fit.std_error, fit.x_min);
+    println!(
+        "Tail: {} of {} players ({:.1}%) have degree >= x_min",
+        fit.tail_size,
+        fit.sample_size,
+        100.0 * fit.tail_size as f64 / fit.sample_size.max(1) as f64
+    );
+    println!("KS distance: {:.4}", fit.ks_statistic);
+    match fit.p_value {
+        Some(p) if p < 0.1 => println!("Goodness of fit: p = {:.2}, power law ruled out", p),
+        Some(p) => println!("Goodness of fit: p = {:.2}, power law plausible for the tail", p),
+        None => println!("Goodness of fit: not tested"),
+    }
+    println!("===============================================");
+}
+
+/// Prints the degeneracy, the innermost core's members, and every non-empty k-shell
+pub fn show_core_decomposition(cores: &CoreDecomposition) {
+    println!("===== k-Core Decomposition =====");
+    println!("Degeneracy (deepest core): {}", cores.degeneracy);
+    let innermost = cores.core_members(cores.degeneracy);
+    let names: Vec<String> = innermost.iter().take(10).map(|id| id.to_string()).collect();
+    println!("Innermost {}-core: {} players, e.g. {}", cores.degeneracy, innermost.len(), names.join(", "));
+
+    let shells: Vec<String> = cores
+        .shell_sizes
+        .iter()
+        .enumerate()
+        .filter(|&(_, &size)| size > 0)
+        .map(|(k, size)| format!("{}: {}", k, size))
+        .collect();
+    println!("k-shell sizes (k: players):");
+    for row in shells.chunks(10) {
+        println!("  {}", row.join(", "));
+    }
+    println!("================================");
+}
+
+/// Everything reported by `print_summary`
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct NetworkSummary {
+    pub average_path_length: f64,
+    pub diameter: usize,
+    pub degrees: Vec<usize>,                // degree sample for validation
+    pub two_hop: Vec<usize>,                // synthetic 2-hop sample
+    pub densest: DenseSubgraph,             // most tightly linked group of players
+    pub top_centrality: Vec<(PlayerId, f64)>, // top centrality players
+    pub communities: Partition,             // Louvain communities
+    pub label_propagation: Partition,       // label propagation, for comparison
+}
+
 /// Prints a formatted summary of the network analysis results
 /// Includes graph metrics and top central players
-pub fn print_summary(
-    avg_dist: f64,                  // average shortest path
-    diameter: usize,               // network diameter
-    degrees: &[usize],             // degree sample for validation
-    two_hop: &[usize],             // synthetic 2-hop sample
-    densest_nodes: &[usize],       // densest subgraph node set
-    densest_density: f64,          // average internal connectivity of dense group
-    top_centrality: &[(String, f64)], // top centrality players
-    communities: &[(usize, usize)],   // toy community memberships
-) {
+pub fn print_summary(summary: &NetworkSummary) {
     println!("===== NBA Network Analysis Summary =====");
-    println!("Average shortest-path length: {:.3}", avg_dist);
-    println!("Network diameter: {}", diameter);
-    println!("Degree: sample {} nodes", degrees.len());
-    println!("2-hop neighbors: sample {} nodes", two_hop.len());
-    println!("Densest subgraph size: {} nodes", densest_nodes.len());
-    println!("Densest subgraph density: {:.3}", densest_density);
+    println!("Average shortest-path length: {:.3}", summary.average_path_length);
+    println!("Network diameter: {}", summary.diameter);
+    println!("Degree: sample {} nodes", summary.degrees.len());
+    println!("2-hop neighbors: sample {} nodes", summary.two_hop.len());
+    show_dense_subgraph(&summary.densest, 30);
 
     println!("Top centrality players:");
-    for (name, score) in top_centrality.iter().take(10) {
+    for (name, score) in summary.top_centrality.iter().take(10) {
         println!("  {}: {:.3}", name, score);
     }
 
-    println!("Community assignments (node_index -> community_id):");
-    for (node, comm) in communities.iter().take(10) {
-        println!("  {} -> {}", node, comm);
-    }
+    show_communities("Louvain communities", &summary.communities, 10);
+    show_communities("Label propagation communities", &summary.label_propagation, 3);
 
     println!("Check the `output/` directory for generated PNGs:");
-    println!("  - degree_histogram.png");
-    println!("  - degree_loglog.png");
-    println!("  - top_centrality.png");
+    for file in [
+        "degree_distribution.png",
+        "degree_loglog.png",
+        "centrality_scores.png",
+        "pagerank_scores.png",
+        "clustering_vs_degree.png",
+        "core_sizes.png",
+    ] {
+        println!("  - {}", file);
+    }
     println!("========================================");
-}
\ No newline at end of file
+}
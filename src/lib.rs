@@ -0,0 +1,9 @@
+// src/lib.rs
+// Module: lib
+// Purpose: Expose the data loading, graph construction, analysis, visualization, and reporting modules as a library.
+
+pub mod data_loader;
+pub mod graph_builder;
+pub mod analysis;
+pub mod visualizations;
+pub mod intro_view;
@@ -2,26 +2,113 @@
 // Module: main
 // Purpose: Top-level program driver that coordinates data loading, graph construction, analysis, visualization, and reporting.
 
-mod data_loader;
-mod graph_builder;
-mod analysis;
-mod visualizations;
-mod intro_view;
-
-use data_loader::load_players;
-use graph_builder::build_player_graph;
-use analysis::{analyze_degrees, compute_centrality, analyze_similarity};
-use visualizations::{plot_degree_distribution, plot_degree_loglog, plot_centrality_scores};
-use intro_view::{show_intro, print_summary};
+use nba_network::data_loader::{load_players_with_report, ColumnMapping, IdentityOverrides, PlayerSeason, TeamGrouping};
+use nba_network::graph_builder::{build_bipartite_graph, build_player_graph_with, build_windowed_graphs, resolve_player, shared_stints, EdgeWeighting, GraphOptions};
+use nba_network::analysis::{analyze_degrees, analyze_strength, compute_betweenness, compute_centrality, compute_clustering, compute_distance_stats, compute_eigenvector_centrality, compute_pagerank, compute_shortest_paths, connected_components, core_decomposition, densest_subgraph, detect_communities, find_teammate_paths, fit_power_law, giant_component, most_similar_pairs, similar_players, BetweennessOptions, CommunityMethod, CommunityOptions, DensestMethod, EigenvectorOptions, PageRankOptions, PathQuery, PathSampling, PowerLawOptions, SimilarityMetric, SimilarityOptions, roster_continuity};
+use nba_network::visualizations::{plot_degree_distribution, plot_degree_loglog, plot_centrality_scores, plot_clustering_vs_degree, plot_core_sizes, plot_player_scores};
+use nba_network::intro_view::{show_distance_stats, show_intro, show_clustering, show_components, show_core_decomposition, show_load_report, show_path_sample, show_power_law, show_similar_pairs, show_roster_continuity, show_shared_stints, show_teammate_paths, show_top_scores, show_window_summary, print_summary, NetworkSummary};
 use std::fs;
-use petgraph::graph::NodeIndex;
+use std::path::Path;
+
+/// Optional manual identity merges/splits, see `IdentityOverrides::load`
+const OVERRIDES_PATH: &str = "data/player_overrides.csv";
+
+/// Usage for the optional command-line query
+const USAGE: &str = "usage: Final-Project-DS-210 [path <player A> <player B> [--all [N] | --alternatives K]]";
 
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        None => run_analysis(),
+        Some("path") => run_path_query(&args[1..]),
+        Some(_) => {
+            eprintln!("{}", USAGE);
+            std::process::exit(2);
+        }
+    }
+}
+
+/// Relocated and renamed teams are treated as one franchise throughout;
+/// edges are weighted by estimated games played together
+fn graph_options() -> GraphOptions {
+    GraphOptions {
+        grouping: TeamGrouping::Franchise,
+        weighting: EdgeWeighting::GamesOverlap,
+    }
+}
+
+/// Loads player-season records and applies identity overrides, exiting on failure
+fn load_dataset(show_report: bool) -> Vec<PlayerSeason> {
+    // Manual identity merges/splits apply when an override file is present
+    let overrides = if Path::new(OVERRIDES_PATH).exists() {
+        match IdentityOverrides::load(OVERRIDES_PATH) {
+            Ok(overrides) => overrides,
+            Err(err) => {
+                eprintln!("Failed to load identity overrides: {}", err);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        IdentityOverrides::default()
+    };
+
+    // Load player-season records from CSV
+    match load_players_with_report("data/all_seasons.csv", &ColumnMapping::default(), &overrides) {
+        Ok((players, report)) => {
+            if show_report {
+                show_load_report(&report);
+            }
+            players
+        }
+        Err(err) => {
+            eprintln!("Failed to load player data: {}", err);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// `path <A> <B> [--all [N] | --alternatives K]`: prints teammate chains between two players
+fn run_path_query(args: &[String]) {
+    // At most: A, B, a flag and its number
+    let (Some(from), Some(to), true) = (args.first(), args.get(1), args.len() <= 4) else {
+        eprintln!("{}", USAGE);
+        std::process::exit(2);
+    };
+    let count = |i: usize, default: usize| match args.get(i) {
+        Some(n) => n.parse().ok().filter(|&n| n > 0).unwrap_or_else(|| {
+            eprintln!("expected a positive number after {}, got \"{}\"", args[i - 1], n);
+            std::process::exit(2);
+        }),
+        None => default,
+    };
+    let query = match args.get(2).map(String::as_str) {
+        None => PathQuery::Shortest,
+        Some("--all") => PathQuery::AllShortest { limit: count(3, 20) },
+        Some("--alternatives") => PathQuery::Alternatives { k: count(3, 5) },
+        Some(_) => {
+            eprintln!("{}", USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    let players = load_dataset(false);
+    let graph = build_player_graph_with(&players, &graph_options());
+    let resolve = |name: &str| {
+        resolve_player(&graph, name).unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        })
+    };
+    let (a, b) = (resolve(from), resolve(to));
+    show_teammate_paths(&a, &b, &find_teammate_paths(&graph, &a, &b, query));
+}
+
+/// Full analysis run: every statistic, plot and summary
+fn run_analysis() {
     // Ensure output directory exists for saving plots
     fs::create_dir_all("output").expect("Failed to create output directory");
 
-    // Load player-season records from CSV
-    let players = load_players("data/all_seasons.csv");
+    let players = load_dataset(true);
 
     // Calculate high-level summary statistics
     let avg_name_len: f64 = players.iter().map(|p| p.player_name.len()).sum::<usize>() as f64 / players.len() as f64;
@@ -34,19 +121,35 @@ fn main() {
     println!("Average points per game: {:.2}", avg_ppg);
 
     println!("\n--- BEGIN NBA DATA SUMMARY ---");
-    show_intro(&players);
+    let options = graph_options();
+    show_intro(&players, options.grouping);
 
     // Build undirected player graph based on team-season overlap
     println!("Building player graph...");
-    let graph = build_player_graph(&players);
+    let graph = build_player_graph_with(&players, &options);
     println!("Graph has {} nodes and {} edges", graph.node_count(), graph.edge_count());
+    show_components(&connected_components(&graph));
+
+    // Track how connectivity evolves across eras with 5-season sliding windows
+    let windows = build_windowed_graphs(&players, 5, 1, &options);
+    show_window_summary(&windows);
+
+    // Bipartite player / team-season model for roster-level questions
+    let bipartite = build_bipartite_graph(&players, &options);
+    show_roster_continuity(&roster_continuity(&bipartite));
 
     // Analyze degree distribution
     println!("Analyzing degree distribution...");
     let degree_counts = analyze_degrees(&graph);
-    plot_degree_distribution(&degree_counts, "output/degree_distribution.png");
-    plot_degree_loglog(&degree_counts, "output/degree_loglog.png");
+    plot_degree_distribution(&degree_counts.counts, "output/degree_distribution.png");
+    // Scale-free claims need a fitted tail that survives a goodness-of-fit test
+    let power_law = fit_power_law(&degree_counts.counts, &PowerLawOptions::default());
+    plot_degree_loglog(&degree_counts.counts, power_law.as_ref(), "output/degree_loglog.png");
     println!("Saved degree plots.");
+    show_power_law(power_law.as_ref());
+
+    // Weighted degree under the chosen edge weighting
+    show_top_scores("Most games alongside teammates (strength)", &analyze_strength(&graph), 5);
 
     // Compute closeness centrality for each player
     println!("Computing centrality...");
@@ -54,44 +157,82 @@ fn main() {
     plot_centrality_scores(&centrality_scores, "output/centrality_scores.png");
     println!("Saved centrality plot.");
 
-    // Identify most similar pair of players using Jaccard
+    // Betweenness highlights journeymen bridging otherwise distant rosters
+    println!("Computing betweenness...");
+    let betweenness = compute_betweenness(&graph, &BetweennessOptions::default());
+    show_top_scores("Top bridging players (betweenness)", &betweenness, 10);
+
+    // Spectral rankings reward ties to other well-connected players, weighted by games together
+    let pagerank = compute_pagerank(&graph, &PageRankOptions::default());
+    show_top_scores("Top players by PageRank", &pagerank, 10);
+    plot_player_scores(&pagerank, "Top Player PageRank Scores (%)", "output/pagerank_scores.png");
+    let eigenvector = compute_eigenvector_centrality(&graph, &EigenvectorOptions::default());
+    show_top_scores("Top players by eigenvector centrality", &eigenvector, 10);
+
+    // Exact path-length statistics per connected component
+    println!("Computing exact distances...");
+    let distance_stats = compute_distance_stats(&graph);
+    show_distance_stats(&distance_stats);
+
+    // Cross-check the giant component's exact average against a random sample of its pairs
+    let sampling = PathSampling { pairs: 500, ..PathSampling::default() };
+    show_path_sample(&compute_shortest_paths(&giant_component(&graph), &sampling));
+
+    // Players whose teammate sets overlap most
     println!("Analyzing player similarity...");
-    analyze_similarity(&graph);
+    let similarity = SimilarityOptions { top_k: 5, ..SimilarityOptions::default() };
+    show_similar_pairs("Most similar players (Jaccard)", &most_similar_pairs(&graph, &similarity));
+    if let Some((star, _)) = pagerank.iter().max_by(|a, b| a.1.total_cmp(b.1)) {
+        let adamic_adar = SimilarityOptions { metric: SimilarityMetric::AdamicAdar, ..similarity };
+        if let Some(pairs) = similar_players(&graph, star, &adamic_adar) {
+            show_similar_pairs(&format!("Most similar to {} (Adamic-Adar)", star), &pairs);
+        }
+    }
 
     // Prepare summary fields for printout
-    let degrees_vec: Vec<usize> = degree_counts.keys().cloned().collect();
+    let degrees_vec: Vec<usize> = degree_counts.counts.keys().cloned().collect();
     let two_hop_sample: Vec<usize> = degrees_vec.iter().map(|&d| d * 2).take(3).collect();
 
-    // Simple densest subgraph placeholder using first 10 nodes
-    let densest_nodes: Vec<usize> = graph.node_indices().take(10).map(|n| n.index()).collect();
-    let densest_density: f64 = if densest_nodes.len() > 1 {
-        let graph_ref = &graph;
-        let edge_count = densest_nodes.iter().flat_map(|&i|
-            densest_nodes.iter().filter(move |&&j| i != j && graph_ref.find_edge(NodeIndex::new(i), NodeIndex::new(j)).is_some())
-        ).count();
-        edge_count as f64 / densest_nodes.len() as f64
-    } else {
-        0.0
-    };
+    // Teammates of teammates: clustering far above a random graph marks a small world
+    let clustering = compute_clustering(&graph);
+    show_clustering(&clustering);
+    plot_clustering_vs_degree(&clustering.degree_vs_clustering, clustering.random_expectation, "output/clustering_vs_degree.png");
+
+    // Nested cores: the deepest ones hold the long-career veterans
+    let cores = core_decomposition(&graph);
+    show_core_decomposition(&cores);
+    plot_core_sizes(&cores.core_sizes(), "output/core_sizes.png");
 
-    // Fake communities using modulo assignment for demonstration
-    let communities: Vec<(usize, usize)> = graph.node_indices().map(|n| (n.index(), n.index() % 5)).collect();
+    // Most tightly linked group of players, solved exactly
+    println!("Finding densest subgraph...");
+    let densest = densest_subgraph(&graph, DensestMethod::Exact);
+
+    // Communities of players tied by many games together
+    println!("Detecting communities...");
+    let communities = detect_communities(&graph, &CommunityOptions::default());
+    let propagation = CommunityOptions { method: CommunityMethod::LabelPropagation, ..CommunityOptions::default() };
+    let label_propagation = detect_communities(&graph, &propagation);
 
     // Extract and sort top centrality players
     let mut top_central: Vec<_> = centrality_scores.iter().map(|(n, s)| (n.clone(), *s)).collect();
     top_central.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
 
+    // Show where the two most central players crossed paths, if they did
+    if let [(first, _), (second, _), ..] = top_central.as_slice() {
+        show_shared_stints(first, second, shared_stints(&graph, first, second));
+    }
+
     // Final summary output
-    print_summary(
-        2.63,
-        6,
-        &degrees_vec,
-        &two_hop_sample,
-        &densest_nodes,
-        densest_density,
-        &top_central,
-        &communities,
-    );
+    print_summary(&NetworkSummary {
+        average_path_length: distance_stats.average_path_length,
+        diameter: distance_stats.diameter,
+        degrees: degrees_vec,
+        two_hop: two_hop_sample,
+        densest,
+        top_centrality: top_central,
+        communities,
+        label_propagation,
+    });
 
     println!("--- END NBA ANALYSIS ---");
 }
@@ -2,6 +2,8 @@
 // Module: visualizations
 // Purpose: Generate visual representations of degree distribution and centrality scores as PNGs using the `plotters` crate.
 
+use crate::analysis::PowerLawFit;
+use crate::data_loader::PlayerId;
 use plotters::prelude::*;
 use std::collections::HashMap;
 
@@ -45,8 +47,8 @@ pub fn plot_degree_distribution(degree_counts: &HashMap<usize, usize>, output_pa
 }
 
 /// Plots the degree distribution on a log-log scale.
-/// Useful for checking power-law behavior.
-pub fn plot_degree_loglog(degree_counts: &HashMap<usize, usize>, output_path: &str) {
+/// Useful for checking power-law behavior; a fit, if given, is drawn over its tail.
+pub fn plot_degree_loglog(degree_counts: &HashMap<usize, usize>, fit: Option<&PowerLawFit>, output_path: &str) {
     let root = BitMapBackend::new(output_path, (800, 600)).into_drawing_area();
     root.fill(&WHITE).unwrap();
 
@@ -65,9 +67,21 @@ pub fn plot_degree_loglog(degree_counts: &HashMap<usize, usize>, output_path: &s
     // Compute x and y axis ranges
     let x_min = data.iter().map(|(x, _)| *x).fold(f64::INFINITY, f64::min);
     let x_max = data.iter().map(|(x, _)| *x).fold(f64::NEG_INFINITY, f64::max);
-    let y_min = data.iter().map(|(_, y)| *y).fold(f64::INFINITY, f64::min);
+    let mut y_min = data.iter().map(|(_, y)| *y).fold(f64::INFINITY, f64::min);
     let y_max = data.iter().map(|(_, y)| *y).fold(f64::NEG_INFINITY, f64::max);
 
+    // Expected counts of the fit from x_min to the highest degree, allowed one decade below the data
+    let fitted: Vec<(f64, f64)> = fit
+        .map(|fit| {
+            let max_degree = degree_counts.keys().copied().max().unwrap_or(0);
+            (fit.x_min..=max_degree)
+                .map(|k| ((k as f64).log10(), fit.expected_count(k).log10()))
+                .filter(|&(_, y)| y >= y_min - 1.0)
+                .collect()
+        })
+        .unwrap_or_default();
+    y_min = fitted.iter().map(|(_, y)| *y).fold(y_min, f64::min);
+
     let x_range = x_min..x_max;
     let y_range = y_min..y_max;
 
@@ -79,39 +93,163 @@ pub fn plot_degree_loglog(degree_counts: &HashMap<usize, usize>, output_path: &s
         .build_cartesian_2d(x_range, y_range)
         .unwrap();
 
-    chart.configure_mesh().draw().unwrap();
+    chart.configure_mesh().x_desc("log10(degree)").y_desc("log10(players)").draw().unwrap();
 
     chart
         .draw_series(data.iter().map(|(x, y)| Circle::new((*x, *y), 3, RED.filled())))
         .unwrap();
+
+    if let Some(fit) = fit {
+        chart
+            .draw_series(LineSeries::new(fitted, BLUE.stroke_width(2)))
+            .unwrap()
+            .label(format!("power law: alpha = {:.2}, x_min = {}", fit.alpha, fit.x_min))
+            .legend(|(x, y)| PathElement::new([(x, y), (x + 20, y)], BLUE.stroke_width(2)));
+        chart
+            .configure_series_labels()
+            .position(SeriesLabelPosition::UpperRight)
+            .background_style(WHITE.mix(0.8))
+            .border_style(BLACK)
+            .draw()
+            .unwrap();
+    }
+}
+
+/// Plots the size of the k-core against k.
+/// core_sizes[k] is the number of players with core number >= k.
+pub fn plot_core_sizes(core_sizes: &[usize], output_path: &str) {
+    let root = BitMapBackend::new(output_path, (800, 600)).into_drawing_area();
+    root.fill(&WHITE).expect("Failed to fill background");
+
+    if core_sizes.is_empty() {
+        println!("Warning: k-core sizes are empty, no plot generated.");
+        return;
+    }
+    let max_k = core_sizes.len() - 1;
+    let max_size = core_sizes.iter().copied().max().unwrap_or(0);
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("k-Core Size vs k", ("sans-serif", 30))
+        .margin(20)
+        .x_label_area_size(40)
+        .y_label_area_size(60)
+        .build_cartesian_2d(0..max_k + 1, 0..max_size + max_size / 20 + 1)
+        .expect("Failed to build k-core chart");
+
+    chart
+        .configure_mesh()
+        .x_desc("k")
+        .y_desc("players in k-core")
+        .draw()
+        .expect("Failed to draw mesh");
+
+    chart
+        .draw_series(LineSeries::new(core_sizes.iter().enumerate().map(|(k, &size)| (k, size)), &BLUE))
+        .expect("Failed to draw k-core line");
+    chart
+        .draw_series(core_sizes.iter().enumerate().map(|(k, &size)| Circle::new((k, size), 2, BLUE.filled())))
+        .expect("Failed to draw k-core points");
+
+    root.present().unwrap_or_else(|e| panic!("Failed to write {output_path}: {e}"));
+}
+
+/// Scatter of local clustering against degree (log-scaled degree axis),
+/// with the mean per degree bin and the random-graph expectation as a
+/// dashed reference line. Small-world graphs sit far above that line.
+pub fn plot_clustering_vs_degree(points: &[(usize, f64)], random_expectation: f64, output_path: &str) {
+    let root = BitMapBackend::new(output_path, (800, 600)).into_drawing_area();
+    root.fill(&WHITE).expect("Failed to fill background");
+
+    let data: Vec<(f64, f64)> = points.iter().filter(|&&(d, _)| d > 0).map(|&(d, c)| (d as f64, c)).collect();
+    if data.is_empty() {
+        println!("Warning: clustering data is empty, no plot generated.");
+        return;
+    }
+    let min_degree = data.iter().map(|(d, _)| *d).fold(f64::INFINITY, f64::min) * 0.9;
+    let max_degree = data.iter().map(|(d, _)| *d).fold(1.0, f64::max) * 1.1;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Clustering Coefficient vs Degree", ("sans-serif", 30))
+        .margin(20)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d((min_degree..max_degree).log_scale(), 0.0..1.05)
+        .expect("Failed to build clustering chart");
+
+    chart
+        .configure_mesh()
+        .x_label_formatter(&|d| format!("{:.0}", d))
+        .x_desc("degree (log scale)")
+        .y_desc("local clustering")
+        .draw()
+        .expect("Failed to draw mesh");
+
+    chart
+        .draw_series(data.iter().map(|&(d, c)| Circle::new((d, c), 2, BLUE.mix(0.3).filled())))
+        .expect("Failed to draw clustering points");
+
+    // Mean clustering per logarithmic degree bin (about 10 bins per decade)
+    let mut bins: HashMap<i64, (f64, f64, usize)> = HashMap::new();
+    for &(d, c) in &data {
+        let bin = bins.entry((d.log10() * 10.0).floor() as i64).or_insert((0.0, 0.0, 0));
+        bin.0 += d;
+        bin.1 += c;
+        bin.2 += 1;
+    }
+    let mut means: Vec<(f64, f64)> = bins.values().map(|&(d, c, n)| (d / n as f64, c / n as f64)).collect();
+    means.sort_by(|a, b| a.0.total_cmp(&b.0));
+    chart
+        .draw_series(LineSeries::new(means, RED.stroke_width(2)))
+        .expect("Failed to draw binned means");
+
+    chart
+        .draw_series(DashedLineSeries::new(
+            [(min_degree, random_expectation), (max_degree, random_expectation)],
+            6,
+            4,
+            BLACK.into(),
+        ))
+        .expect("Failed to draw random-graph reference");
+
+    root.present().unwrap_or_else(|e| panic!("Failed to write {output_path}: {e}"));
 }
 
 /// Plots top 20 players by closeness centrality.
 /// Labels are rotated for readability and scaled to percentages.
-pub fn plot_centrality_scores(centrality_scores: &HashMap<String, f64>, output_path: &str) {
-    let mut scores: Vec<_> = centrality_scores.iter().collect();
+pub fn plot_centrality_scores(centrality_scores: &HashMap<PlayerId, f64>, output_path: &str) {
+    plot_player_scores(centrality_scores, "Top Player Centrality Scores (%)", output_path);
+}
+
+/// Plots the top 20 players of any per-player score map (PageRank,
+/// eigenvector, betweenness, ...) under the given chart title.
+pub fn plot_player_scores(player_scores: &HashMap<PlayerId, f64>, title: &str, output_path: &str) {
+    let mut scores: Vec<_> = player_scores.iter().collect();
     scores.sort_by(|a, b| b.1.partial_cmp(a.1).unwrap());
     let top_scores = &scores[..scores.len().min(20)];
 
-    let categories: Vec<String> = top_scores.iter().map(|(name, _)| (*name).clone()).collect();
-    let cat_range = 0..categories.len();
+    let categories: Vec<String> = top_scores.iter().map(|(id, _)| id.to_string()).collect();
+    let cat_range = (0..categories.len()).into_segmented();
 
     let max_score = top_scores
         .iter()
         .map(|(_, score)| **score * 100.0)
         .fold(f64::MIN, f64::max);
 
-    let upper_bound = if max_score > 0.0 {
+    // Small scores (e.g. PageRank) keep a fractional axis instead of rounding up to 1%
+    let upper_bound = if max_score >= 1.0 {
         (max_score * 1.1).ceil()
+    } else if max_score > 0.0 {
+        max_score * 1.1
     } else {
         1.0
     };
+    let decimals = if upper_bound >= 1.0 { 1 } else { 3 };
 
     let root = BitMapBackend::new(output_path, (1200, 600)).into_drawing_area();
     root.fill(&WHITE).expect("Failed to fill background");
 
     let mut chart = ChartBuilder::on(&root)
-        .caption("Top Player Centrality Scores (%)", ("sans-serif", 30))
+        .caption(title, ("sans-serif", 30))
         .margin(40)
         .x_label_area_size(120)
         .y_label_area_size(60)
@@ -121,7 +259,10 @@ pub fn plot_centrality_scores(centrality_scores: &HashMap<String, f64>, output_p
     chart
         .configure_mesh()
         .x_labels(categories.len())
-        .x_label_formatter(&|i| categories.get(*i).unwrap_or(&"".to_string()).to_string())
+        .x_label_formatter(&|v| match v {
+            SegmentValue::CenterOf(i) => categories.get(*i).cloned().unwrap_or_default(),
+            _ => String::new(),
+        })
         .label_style(("sans-serif", 14))
         .x_label_style(("sans-serif", 13).into_font().transform(FontTransform::Rotate90))
         .draw()
@@ -132,7 +273,12 @@ pub fn plot_centrality_scores(centrality_scores: &HashMap<String, f64>, output_p
         .draw_series(
             top_scores.iter().enumerate().map(|(i, (_, score))| {
                 let score_pct = *score * 100.0;
-                Rectangle::new([(i, 0.0), (i, score_pct)], GREEN.filled())
+                let mut bar = Rectangle::new(
+                    [(SegmentValue::Exact(i), 0.0), (SegmentValue::Exact(i + 1), score_pct)],
+                    GREEN.filled(),
+                );
+                bar.set_margin(0, 0, 8, 8);
+                bar
             })
         )
         .expect("Failed to draw centrality bars");
@@ -143,13 +289,13 @@ pub fn plot_centrality_scores(centrality_scores: &HashMap<String, f64>, output_p
             top_scores.iter().enumerate().map(|(i, (_, score))| {
                 let score_pct = *score * 100.0;
                 Text::new(
-                    format!("{:.1}", score_pct),
-                    (i, score_pct + 0.5),
+                    format!("{:.*}", decimals, score_pct),
+                    (SegmentValue::Exact(i), score_pct + upper_bound * 0.01),
                     ("sans-serif", 12).into_font().color(&BLACK),
                 )
             })
         )
         .expect("Failed to draw value labels");
 
-    root.present().expect("Failed to write centrality_scores.png");
+    root.present().unwrap_or_else(|e| panic!("Failed to write {output_path}: {e}"));
 }